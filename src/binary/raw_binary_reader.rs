@@ -23,8 +23,11 @@ use crate::{
 use std::io;
 
 use crate::raw_symbol_token::RawSymbolToken;
+use crate::types::coefficient::Sign;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
+use num_bigint::BigUint;
+use num_traits::Zero;
 use std::ops::Range;
 
 /// Information about the value over which the RawBinaryReader is currently positioned.
@@ -941,6 +944,30 @@ where
         self.cursor.value.is_null
     }
 
+    /// If the reader is positioned over a non-null integer, returns its sign and magnitude;
+    /// otherwise, returns `Ok(None)`. Unlike [read_i64](RawReader::read_i64), this can represent
+    /// integers of any size, including those whose magnitude is wider than 8 bytes. Like the text
+    /// reader's equivalent method, a zero (including a binary negative zero) is reported with a
+    /// positive sign.
+    pub fn read_int_parts(&mut self) -> IonResult<Option<(Sign, BigUint)>> {
+        read_safety_checks!(self, IonType::Integer);
+
+        // The magnitude is a big-endian UInt of any length, so it's decoded directly rather than
+        // through DecodedUInt, which only supports magnitudes that fit in a u64.
+        let number_of_bytes = self.cursor.value.value_length;
+        let magnitude =
+            self.read_slice(number_of_bytes, |bytes| Ok(BigUint::from_bytes_be(bytes)))?;
+
+        use self::IonTypeCode::*;
+        let sign = match self.cursor.value.header.ion_type_code {
+            NegativeInteger if !magnitude.is_zero() => Sign::Negative,
+            PositiveInteger | NegativeInteger => Sign::Positive,
+            itc => unreachable!("Unexpected IonTypeCode: {:?}", itc),
+        };
+
+        Ok(Some((sign, magnitude)))
+    }
+
     fn finished_reading_value(&mut self) -> bool {
         self.cursor.value.value_length > 0
             && self.cursor.bytes_read >= self.cursor.value.value_end_exclusive()
@@ -1191,9 +1218,13 @@ mod tests {
     use crate::raw_reader::{RawReader, RawStreamItem, RawStreamItem::*};
     use crate::raw_symbol_token::local_sid_token;
     use crate::result::{IonError, IonResult};
+    use crate::text::raw_text_reader::{IntegerOverflowMode, RawTextReaderBuilder};
+    use crate::types::coefficient::Sign;
     use crate::types::decimal::Decimal;
     use crate::types::timestamp::Timestamp;
     use crate::types::IonType;
+    use num_bigint::BigUint;
+    use rstest::*;
     use std::convert::TryInto;
 
     type TestDataSource = io::Cursor<Vec<u8>>;
@@ -1303,6 +1334,92 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_int_parts() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x31, 0x01, // -1
+            0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // u64::MAX
+            0x20, // 0
+            0x2F, // null.int
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            cursor.read_int_parts()?,
+            Some((Sign::Negative, BigUint::from(1u64)))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            cursor.read_int_parts()?,
+            Some((Sign::Positive, BigUint::from(u64::MAX)))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            cursor.read_int_parts()?,
+            Some((Sign::Positive, BigUint::from(0u64)))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, true)));
+        assert_eq!(cursor.read_int_parts()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_int_parts_wider_than_u64() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x39, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // -(2^64)
+            0x2E, 0x90, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFF, 0xFF, 0xFF, 0xFF, // 2^128 - 1
+            0x21, 0x05, // 5
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            cursor.read_int_parts()?,
+            Some((Sign::Negative, BigUint::from(u64::MAX) + 1u32))
+        );
+        // read_i64 still rejects integers that don't fit
+        assert!(cursor.read_i64().is_err());
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            cursor.read_int_parts()?,
+            Some((Sign::Positive, BigUint::from(u128::MAX)))
+        );
+        // The reader is still in step with the stream
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(5));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::i64_min(
+        &[0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        "-9223372036854775808",
+    )]
+    #[case::above_u64_max(
+        &[0x29, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        "18446744073709551616",
+    )]
+    #[case::below_negative_u64_max(
+        &[0x39, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        "-0x1_0000_0000_0000_0000",
+    )]
+    fn test_read_int_parts_matches_text_reader(
+        #[case] binary: &[u8],
+        #[case] text: &str,
+    ) -> IonResult<()> {
+        let mut binary_reader = ion_cursor_for(binary);
+        // The text reader only accepts integers that don't fit in an i64 when clamping is enabled,
+        // but read_int_parts always returns their exact value.
+        let mut text_reader = RawTextReaderBuilder::new()
+            .with_integer_overflow_mode(IntegerOverflowMode::Clamp)
+            .build(text);
+        assert_eq!(binary_reader.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(text_reader.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            binary_reader.read_int_parts()?,
+            text_reader.read_int_parts()?
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_read_f64_zero() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x40]);
@@ -9,11 +9,15 @@ use chrono::{DateTime, FixedOffset};
 use crate::binary::constants::v1_0::IVM;
 use crate::binary::uint::DecodedUInt;
 use crate::binary::var_uint::VarUInt;
-use crate::result::{illegal_operation, IonResult};
+use crate::constants::v1_0::system_symbol_ids;
+use crate::result::{illegal_operation, illegal_operation_raw, IonResult};
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
-use crate::IonType;
+use crate::value::owned::{OwnedElement, OwnedSymbolToken, OwnedValue};
+use crate::value::{AnyInt, Element, Sequence, Struct, SymbolToken};
+use crate::{IonType, SymbolTable};
+use num_traits::ToPrimitive;
 
 use super::decimal::DecimalBinaryEncoder;
 use super::timestamp::TimestampBinaryEncoder;
@@ -793,6 +797,156 @@ impl<W: Write> BinarySystemWriter<W> {
     }
 }
 
+/// A binary Ion writer that manages a local symbol table on behalf of the user. Symbol text found
+/// in annotations, field names, and symbol values is added to the symbol table as it is
+/// encountered; any new symbols are declared in a local symbol table append that precedes the
+/// value that uses them.
+pub struct BinaryWriter<W: Write> {
+    system_writer: BinarySystemWriter<W>,
+    symbol_table: SymbolTable,
+}
+
+impl<W: Write> BinaryWriter<W> {
+    /// Creates a new BinaryWriter that will write its encoded output to the provided
+    /// io::Write sink.
+    pub fn new(out: W) -> BinaryWriter<W> {
+        BinaryWriter {
+            system_writer: BinarySystemWriter::new(out),
+            symbol_table: SymbolTable::new(),
+        }
+    }
+
+    /// Writes the provided element (and any values nested inside of it) as a top-level value.
+    /// If the element contains symbol text that is not yet in the writer's symbol table, a local
+    /// symbol table declaring that text will be written first.
+    pub fn write_element(&mut self, element: &OwnedElement) -> IonResult<()> {
+        let mut new_symbols = Vec::new();
+        self.intern_symbols(element, &mut new_symbols);
+        if !new_symbols.is_empty() {
+            self.write_lst_append(&new_symbols)?;
+        }
+        self.write_value(element)
+    }
+
+    /// Returns a reference to the underlying io::Write implementation.
+    pub fn output(&self) -> &W {
+        self.system_writer.output()
+    }
+
+    /// Writes any buffered data to the sink.
+    pub fn flush(&mut self) -> IonResult<()> {
+        self.system_writer.flush()
+    }
+
+    // Adds any symbol text in the element that is not already in the symbol table, appending the
+    // text of each new symbol to `new_symbols`.
+    fn intern_symbols(&mut self, element: &OwnedElement, new_symbols: &mut Vec<String>) {
+        for annotation in element.annotations() {
+            self.intern_symbol(annotation, new_symbols);
+        }
+        match element.value() {
+            OwnedValue::Symbol(token) => self.intern_symbol(token, new_symbols),
+            OwnedValue::List(sequence) | OwnedValue::SExpression(sequence) => {
+                for child in sequence.iter() {
+                    self.intern_symbols(child, new_symbols);
+                }
+            }
+            OwnedValue::Struct(structure) => {
+                for (field_name, child) in structure.iter() {
+                    self.intern_symbol(field_name, new_symbols);
+                    self.intern_symbols(child, new_symbols);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn intern_symbol(&mut self, token: &OwnedSymbolToken, new_symbols: &mut Vec<String>) {
+        if let Some(text) = token.text() {
+            if self.symbol_table.sid_for(&text).is_none() {
+                let _sid = self.symbol_table.intern(text.to_string());
+                new_symbols.push(text.to_string());
+            }
+        }
+    }
+
+    // Writes a local symbol table that appends `symbols` to the current symbol table:
+    //     $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["your", "strings", "here"]}
+    fn write_lst_append(&mut self, symbols: &[String]) -> IonResult<()> {
+        let writer = &mut self.system_writer;
+        writer.set_annotation_ids(&[system_symbol_ids::ION_SYMBOL_TABLE]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_id(system_symbol_ids::IMPORTS);
+        writer.write_symbol_id(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        writer.set_field_id(system_symbol_ids::SYMBOLS);
+        writer.step_in(IonType::List)?;
+        for symbol in symbols {
+            writer.write_string(symbol)?;
+        }
+        writer.step_out()?;
+        writer.step_out()
+    }
+
+    // Returns the symbol ID for the provided token. Tokens without text or a local symbol ID are
+    // written as symbol zero.
+    fn sid_for(&self, token: &OwnedSymbolToken) -> IonResult<SymbolId> {
+        match (token.text(), token.local_sid()) {
+            (Some(text), _) => self.symbol_table.sid_for(&text).ok_or_else(|| {
+                illegal_operation_raw(format!("Symbol '{}' is not in the symbol table.", text))
+            }),
+            (None, Some(sid)) => Ok(sid),
+            (None, None) => Ok(0),
+        }
+    }
+
+    fn write_value(&mut self, element: &OwnedElement) -> IonResult<()> {
+        let annotations = element
+            .annotations()
+            .map(|annotation| self.sid_for(annotation))
+            .collect::<IonResult<Vec<SymbolId>>>()?;
+        self.system_writer.set_annotation_ids(&annotations);
+        let writer = &mut self.system_writer;
+        match element.value() {
+            OwnedValue::Null(ion_type) => writer.write_null(*ion_type),
+            OwnedValue::Boolean(value) => writer.write_bool(*value),
+            OwnedValue::Integer(AnyInt::I64(value)) => writer.write_i64(*value),
+            OwnedValue::Integer(AnyInt::BigInt(value)) => match value.to_i64() {
+                Some(value) => writer.write_i64(value),
+                None => illegal_operation(format!(
+                    "Writing integers that do not fit in an i64 ({}) is not yet supported.",
+                    value
+                )),
+            },
+            OwnedValue::Float(value) => writer.write_f64(*value),
+            OwnedValue::Decimal(value) => writer.write_decimal(value),
+            OwnedValue::Timestamp(value) => writer.write_timestamp(value),
+            OwnedValue::String(value) => writer.write_string(value),
+            OwnedValue::Symbol(token) => {
+                let sid = self.sid_for(token)?;
+                self.system_writer.write_symbol_id(sid)
+            }
+            OwnedValue::Blob(bytes) => writer.write_blob(bytes),
+            OwnedValue::Clob(bytes) => writer.write_clob(bytes),
+            OwnedValue::List(sequence) | OwnedValue::SExpression(sequence) => {
+                writer.step_in(element.ion_type())?;
+                for child in sequence.iter() {
+                    self.write_value(child)?;
+                }
+                self.system_writer.step_out()
+            }
+            OwnedValue::Struct(structure) => {
+                writer.step_in(IonType::Struct)?;
+                for (field_name, child) in structure.iter() {
+                    let field_id = self.sid_for(field_name)?;
+                    self.system_writer.set_field_id(field_id);
+                    self.write_value(child)?;
+                }
+                self.system_writer.step_out()
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod writer_tests {
     use std::fmt::Debug;
@@ -803,6 +957,10 @@ mod writer_tests {
 
     use super::*;
     use crate::raw_symbol_token::{local_sid_token, RawSymbolToken};
+    use crate::system_reader::{equivalent_streams, SystemReader};
+    use crate::value::owned::text_token;
+    use crate::value::owned_reader::OwnedElementReader;
+    use crate::value::Builder;
     use num_traits::Float;
     use std::convert::TryInto;
 
@@ -1372,4 +1530,47 @@ mod writer_tests {
             },
         )
     }
+
+    #[test]
+    fn binary_writer_write_element() -> IonResult<()> {
+        let element = OwnedElement::new_struct(vec![
+            ("name", OwnedElement::new_string("widget")),
+            (
+                "tags",
+                OwnedElement::new_list(vec![
+                    OwnedElement::new_symbol(text_token("red")),
+                    OwnedElement::new_symbol(text_token("blue")),
+                ])
+                .with_annotations(vec![text_token("colors")]),
+            ),
+            (
+                "dimensions",
+                OwnedElement::new_sexp(vec![
+                    OwnedElement::new_i64(3),
+                    OwnedElement::new_f64(4.5),
+                    OwnedElement::new_decimal(Decimal::new(55, -1)),
+                    OwnedElement::new_null(IonType::Struct),
+                ]),
+            ),
+            ("red", OwnedElement::new_bool(true)),
+        ])
+        .with_annotations(vec![text_token("product"), text_token("v1")]);
+        // The second element reuses some symbols and introduces new ones.
+        let second_element = OwnedElement::new_symbol(text_token("blue"))
+            .with_annotations(vec![text_token("colors"), text_token("favorite")]);
+        let elements = vec![element, second_element, OwnedElement::new_blob(b"hi")];
+
+        let mut buffer = vec![];
+        let mut writer = BinaryWriter::new(&mut buffer);
+        for element in &elements {
+            writer.write_element(element)?;
+        }
+        writer.flush()?;
+
+        let binary_reader =
+            &mut SystemReader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        let elements_reader = &mut SystemReader::new(OwnedElementReader::new(&elements));
+        assert!(equivalent_streams(binary_reader, elements_reader)?);
+        Ok(())
+    }
 }
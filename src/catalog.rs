@@ -0,0 +1,9 @@
+use crate::symbol_table::SharedSymbolTable;
+
+/// A collection of shared symbol tables. When a local symbol table imports a shared symbol table
+/// by name and version, the reader consults its `Catalog` to find the symbols being imported.
+pub trait Catalog {
+    /// Returns the shared symbol table with the specified name and version, if the catalog
+    /// contains it.
+    fn get_table(&self, name: &str, version: u32) -> Option<&SharedSymbolTable>;
+}
@@ -0,0 +1,85 @@
+use crate::result::{decoding_error, IonResult};
+use crate::{IonType, RawReader};
+
+/// A type that can be constructed from the value on which an Ion reader is currently positioned.
+///
+/// Implementations read the reader's current value; they do not call `next()` to advance it.
+/// This trait is implemented for a handful of common primitive types and is intended to serve as
+/// the foundation for deserializing user-defined types.
+pub trait IonDeserialize: Sized {
+    /// Reads the reader's current value as an instance of `Self`. If the current value is null or
+    /// has an incompatible type, returns a decoding error.
+    fn deserialize<R: RawReader>(reader: &mut R) -> IonResult<Self>;
+}
+
+// Converts the `Option` returned by a reader's `read_*` method into an `IonResult`, reporting
+// the type that was expected if there was no value to read.
+fn require<R: RawReader, T>(reader: &R, expected: IonType, value: Option<T>) -> IonResult<T> {
+    match value {
+        Some(value) => Ok(value),
+        None => decoding_error(format!(
+            "Cannot deserialize a {}: the reader is positioned over {:?} (is_null: {})",
+            expected,
+            reader.ion_type(),
+            reader.is_null()
+        )),
+    }
+}
+
+impl IonDeserialize for i64 {
+    fn deserialize<R: RawReader>(reader: &mut R) -> IonResult<Self> {
+        let value = reader.read_i64()?;
+        require(reader, IonType::Integer, value)
+    }
+}
+
+impl IonDeserialize for f64 {
+    fn deserialize<R: RawReader>(reader: &mut R) -> IonResult<Self> {
+        let value = reader.read_f64()?;
+        require(reader, IonType::Float, value)
+    }
+}
+
+impl IonDeserialize for bool {
+    fn deserialize<R: RawReader>(reader: &mut R) -> IonResult<Self> {
+        let value = reader.read_bool()?;
+        require(reader, IonType::Boolean, value)
+    }
+}
+
+impl IonDeserialize for String {
+    fn deserialize<R: RawReader>(reader: &mut R) -> IonResult<Self> {
+        let value = reader.read_string()?;
+        require(reader, IonType::String, value)
+    }
+}
+
+#[cfg(test)]
+mod ion_deserialize_tests {
+    use super::*;
+    use crate::text::raw_text_reader::RawTextReader;
+
+    #[test]
+    fn test_deserialize_primitives() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("5 true \"x\" 2.5e0");
+        reader.next()?;
+        assert_eq!(i64::deserialize(reader)?, 5);
+        reader.next()?;
+        assert!(bool::deserialize(reader)?);
+        reader.next()?;
+        assert_eq!(String::deserialize(reader)?, "x".to_string());
+        reader.next()?;
+        assert_eq!(reader.try_read_typed::<f64>()?, 2.5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_type_mismatch() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("\"5\" null.int");
+        reader.next()?;
+        assert!(i64::deserialize(reader).is_err());
+        reader.next()?;
+        assert!(i64::deserialize(reader).is_err());
+        Ok(())
+    }
+}
@@ -22,18 +22,24 @@ pub mod text;
 pub mod types;
 pub mod value;
 
+mod catalog;
 pub mod constants;
+mod ion_deserialize;
 mod raw_symbol_token;
 mod reader;
 mod symbol_table;
 mod system_reader;
 
 pub use binary::raw_binary_reader::RawBinaryReader;
+pub use catalog::Catalog;
 pub use data_source::IonDataSource;
+pub use ion_deserialize::IonDeserialize;
 pub use raw_reader::RawReader;
 pub use reader::Reader;
-pub use symbol_table::SymbolTable;
-pub use system_reader::{SystemReader, SystemStreamItem};
+pub use symbol_table::{SharedSymbolTable, SymbolTable};
+pub use system_reader::{
+    equivalent_streams, EncodingEvent, SymbolResolutionPolicy, SystemReader, SystemStreamItem,
+};
 pub use types::IonType;
 
 /// Re-exports of third party dependencies that are part of our public API.
@@ -55,6 +55,15 @@ impl SymbolTable {
         id
     }
 
+    /// Assigns the provided text to the next available symbol ID, even if the text is already
+    /// associated with a lower symbol ID. Returns the new symbol ID.
+    pub fn add_symbol(&mut self, text: String) -> SymbolId {
+        let sid = self.symbols_by_id.len();
+        self.ids_by_text.entry(text.clone()).or_insert(sid);
+        self.symbols_by_id.push(Some(text));
+        sid
+    }
+
     /// Assigns unknown text to the next available symbol ID.
     pub fn add_placeholder(&mut self) -> SymbolId {
         let sid = self.symbols_by_id.len();
@@ -111,3 +120,42 @@ impl SymbolTable {
         self.symbols_by_id.len()
     }
 }
+
+/// A named, versioned list of symbols that local symbol tables can import.
+/// Shared symbol tables are made available to a reader via a [Catalog](crate::Catalog).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedSymbolTable {
+    name: String,
+    version: u32,
+    symbols: Vec<Option<String>>,
+}
+
+impl SharedSymbolTable {
+    pub fn new<S: Into<String>>(
+        name: S,
+        version: u32,
+        symbols: Vec<Option<String>>,
+    ) -> SharedSymbolTable {
+        SharedSymbolTable {
+            name: name.into(),
+            version,
+            symbols,
+        }
+    }
+
+    /// Returns the name that local symbol tables use to import this table.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of this table.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns a slice of references to the symbol text stored in the table. The first symbol in
+    /// the slice is assigned the lowest symbol ID when the table is imported.
+    pub fn symbols(&self) -> &[Option<String>] {
+        &self.symbols
+    }
+}
@@ -5,7 +5,12 @@ use crate::result::{decoding_error, illegal_operation, IonResult};
 use crate::system_reader::LstPosition::*;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
-use crate::{IonType, RawBinaryReader, RawReader, SymbolTable};
+use crate::value::owned::{
+    local_sid_token, text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedSymbolToken,
+    OwnedValue,
+};
+use crate::value::{AnyInt, Element, Sequence, Struct};
+use crate::{Catalog, IonType, RawBinaryReader, RawReader, SymbolTable};
 use bigdecimal::BigDecimal;
 use delegate::delegate;
 use std::io;
@@ -37,6 +42,12 @@ enum LstPosition {
     /// Inside the `imports` field
     ProcessingLstImports,
 
+    /// Inside the `imports` field and positioned at an import struct but have not yet stepped in
+    AtLstImport,
+
+    /// Inside one of the import structs in the `imports` field
+    ProcessingLstImport,
+
     /// Inside an $ion_symbol_table and positioned at the `symbols` field but have not yet stepped in
     AtLstSymbols,
 
@@ -68,9 +79,51 @@ pub enum SystemStreamItem {
     Value(IonType, bool),
 }
 
+/// Describes a change to the encoding context that a [SystemReader] has processed. See
+/// [SystemReader::on_encoding_event].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EncodingEvent {
+    /// An Ion Version Marker specifying the (major, minor) version of the data that follows.
+    /// An IVM also resets the symbol table to the system symbol table.
+    IonVersion(u8, u8),
+    /// A local symbol table replaced the current symbol table with the system symbol table. This
+    /// is always followed by a `SymbolTableAppend` describing the symbols the LST defined.
+    SymbolTableReset,
+    /// A local symbol table added the specified number of symbols (including any imported
+    /// symbols) to the current symbol table.
+    SymbolTableAppend(usize),
+}
+
+/// Determines how a [SystemReader] handles a symbol ID whose text cannot be resolved, either
+/// because the symbol ID is out of range or because its text is unknown.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SymbolResolutionPolicy {
+    /// Raise an error.
+    Error,
+    /// Return the symbol ID as-is. This is the default.
+    ReturnSymbolId,
+    /// Substitute placeholder text of the form `$NN`, where `NN` is the symbol ID.
+    Placeholder,
+}
+
+// Not derived because of the MSRV; see clippy.toml.
+impl Default for SymbolResolutionPolicy {
+    fn default() -> Self {
+        SymbolResolutionPolicy::ReturnSymbolId
+    }
+}
+
+// Describes a shared symbol table that a local symbol table is importing. Each field is populated
+// as the corresponding field of the import struct is read; any of them may be missing or invalid.
+#[derive(Default)]
+struct LstImport {
+    name: Option<String>,
+    version: Option<i64>,
+    max_id: Option<i64>,
+}
+
 // Stores information that has been read from a local symbol table that is currently being
 // processed.
-// TODO: Support shared symbol table imports.
 struct LstData {
     // The reader's position within the LST.
     state: LstPosition,
@@ -79,6 +132,8 @@ struct LstData {
     // added to the table. `is_append` is only set to true if the LST's `imports` field has
     // the symbol `$ion_symbol_table` as its value.
     is_append: bool,
+    // The shared symbol tables this LST imports, in the order they appear in the `imports` list.
+    imports: Vec<LstImport>,
     // All of the new symbols being defined in this LST. These pending symbols are buffered in a Vec
     // because the `symbols` field of the LST can appear before the `imports` field but the `imports`
     // field MUST be processed first.
@@ -96,6 +151,7 @@ impl LstData {
     fn new() -> LstData {
         LstData {
             is_append: false,
+            imports: vec![],
             symbols: vec![],
             state: LstPosition::NotReadingAnLst,
             current_symbol: RawSymbolToken::SymbolId(0),
@@ -125,8 +181,21 @@ pub struct SystemReader<R: RawReader> {
     symbol_table: SymbolTable,
     // Information about the local symbol table we're currently reading, if any
     lst: LstData,
+    // Used to find the shared symbol tables that local symbol tables import, if provided
+    catalog: Option<Box<dyn Catalog + Send>>,
+    // How to handle symbol IDs whose text cannot be resolved
+    symbol_resolution_policy: SymbolResolutionPolicy,
+    // Called each time the reader processes an IVM or a local symbol table, if provided
+    encoding_event_handler: Option<Box<dyn FnMut(EncodingEvent) + Send>>,
+    // The largest `max_id` that an import in a local symbol table may declare
+    max_imported_symbols: usize,
 }
 
+// A placeholder is added to the symbol table for each symbol ID that an import declares but that
+// its shared symbol table cannot provide. This default keeps a single import from allocating
+// gigabytes of placeholders.
+const DEFAULT_MAX_IMPORTED_SYMBOLS: usize = 1_000_000;
+
 // FIXME: The `read_datetime` method is deprecated. However, this function body is generated by a
 // macro, making it impossible to apply this #[allow(deprecated)] more narrowly. When `read_datetime`
 // is removed, this annotation should be removed too.
@@ -137,6 +206,52 @@ impl<C: RawReader> SystemReader<C> {
             raw_reader,
             symbol_table: SymbolTable::new(),
             lst: LstData::new(),
+            catalog: None,
+            symbol_resolution_policy: SymbolResolutionPolicy::default(),
+            encoding_event_handler: None,
+            max_imported_symbols: DEFAULT_MAX_IMPORTED_SYMBOLS,
+        }
+    }
+
+    /// Configures the reader to resolve shared symbol table imports using the provided [Catalog].
+    /// If a local symbol table imports a shared symbol table that is not in the catalog (or no
+    /// catalog has been provided), the imported symbol IDs will have unknown text.
+    pub fn with_catalog(mut self, catalog: Box<dyn Catalog + Send>) -> SystemReader<C> {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// Configures how the reader's symbol-reading methods handle symbol IDs whose text cannot be
+    /// resolved. See [SymbolResolutionPolicy] for the available options; the default is
+    /// [SymbolResolutionPolicy::ReturnSymbolId].
+    pub fn with_symbol_resolution_policy(
+        mut self,
+        policy: SymbolResolutionPolicy,
+    ) -> SystemReader<C> {
+        self.symbol_resolution_policy = policy;
+        self
+    }
+
+    /// Sets the largest `max_id` that a shared symbol table import may declare. The reader adds
+    /// an entry to the current symbol table for each of the symbol IDs an import occupies, so this
+    /// limits the memory that a single import can cause the reader to allocate. If an import
+    /// declares a larger `max_id`, the reader will return a decoding error. Defaults to 1,000,000.
+    pub fn with_max_imported_symbols(mut self, max_imported_symbols: usize) -> SystemReader<C> {
+        self.max_imported_symbols = max_imported_symbols;
+        self
+    }
+
+    /// Registers a callback that will be invoked each time the reader processes an Ion Version
+    /// Marker or finishes processing a local symbol table. This provides visibility into the
+    /// encoding context for diagnostic tooling. Registering a new callback replaces the previous
+    /// one.
+    pub fn on_encoding_event(&mut self, f: impl FnMut(EncodingEvent) + Send + 'static) {
+        self.encoding_event_handler = Some(Box::new(f));
+    }
+
+    fn emit_encoding_event(&mut self, event: EncodingEvent) {
+        if let Some(handler) = self.encoding_event_handler.as_mut() {
+            handler(event);
         }
     }
 
@@ -185,10 +300,15 @@ impl<C: RawReader> SystemReader<C> {
                     self.finish_reading_current_level()?;
                 }
             }
+            AtLstImport => {
+                // If the reader is positioned over an import struct when next() is called, we
+                // need to read its fields instead of skipping it.
+                self.step_in()?;
+                self.finish_reading_current_level()?;
+                self.step_out()?;
+            }
             _ => {
-                // Allow values at depths > 1 to be skipped.
-                // TODO: Process import structs that live at depth=2
-                //       imports: [{name:_, version:_, max_id:_}, {name:_, version:_, max_id:_}]
+                // Allow any other values at depths > 1 to be skipped.
             }
         }
 
@@ -255,8 +375,20 @@ impl<C: RawReader> SystemReader<C> {
                     self.lst.state = BetweenLstFields;
                 }
             }
-            ProcessingLstImports => {
-                todo!("Support shared symbol table imports.");
+            ProcessingLstImports | AtLstImport => {
+                // We're in the `imports` list. Each non-null struct at depth=2 describes a shared
+                // symbol table to import; other values are ignored.
+                if let (2, IonType::Struct, false) = (self.depth(), ion_type, is_null) {
+                    self.lst.imports.push(LstImport::default());
+                    self.lst.state = AtLstImport;
+                } else {
+                    self.lst.state = ProcessingLstImports;
+                }
+            }
+            ProcessingLstImport => {
+                // We're inside an import struct. If this is one of the fields that describes the
+                // shared symbol table being imported, store its value.
+                self.process_import_field()?;
             }
             ProcessingLstSymbols => {
                 // We're in the `symbols` list.
@@ -295,20 +427,68 @@ impl<C: RawReader> SystemReader<C> {
                     self.lst.is_append = true;
                 }
             }
-            IonType::List => {
-                // Once this is supported, this branch will do nothing because the list either
-                // be processed when the user steps into/through it or when they try to skip over
-                // it, not when it's first encountered. For now though, we fail because this
-                // feature is not yet supported.
-                todo!("Support shared symbol table imports.");
-            }
             _ => {
-                // Non-list, non-symbol values for the `imports` field are ignored.
+                // If the `imports` field value is a list, it will be processed when the user steps
+                // into/through it or when they try to skip over it, not when it's first
+                // encountered. Other values for the `imports` field are ignored.
             }
         };
         Ok(())
     }
 
+    // Returns true if the reader is positioned over the `name`, `version`, or `max_id` field of an
+    // import struct. The system reader consumes the values of these fields as it encounters them.
+    fn current_import_field_was_consumed(&self) -> bool {
+        if self.lst.state != ProcessingLstImport || self.depth() != 3 || self.is_null() {
+            return false;
+        }
+        match (self.raw_reader.field_name(), self.raw_reader.ion_type()) {
+            (Some(field_name), Some(IonType::String)) => {
+                field_name.matches(system_symbol_ids::NAME, "name")
+            }
+            (Some(field_name), Some(IonType::Integer)) => {
+                field_name.matches(system_symbol_ids::VERSION, "version")
+                    || field_name.matches(system_symbol_ids::MAX_ID, "max_id")
+            }
+            _ => false,
+        }
+    }
+
+    // Called when the system reader advances to a value inside an import struct. If the value
+    // is the import's `name`, `version`, or `max_id`, stores it in the current import.
+    fn process_import_field(&mut self) -> IonResult<()> {
+        if !self.current_import_field_was_consumed() {
+            return Ok(());
+        }
+        if self.raw_reader.ion_type() == Some(IonType::String) {
+            self.load_current_string()?;
+            let name = self.lst.current_string.clone();
+            self.current_import().name = Some(name);
+            return Ok(());
+        }
+        self.load_current_int()?;
+        let value = self.lst.current_int;
+        let is_version = self
+            .raw_reader
+            .field_name()
+            .map(|field_name| field_name.matches(system_symbol_ids::VERSION, "version"))
+            .unwrap_or(false);
+        if is_version {
+            self.current_import().version = Some(value);
+        } else {
+            self.current_import().max_id = Some(value);
+        }
+        Ok(())
+    }
+
+    // Returns the import whose struct the reader is currently inside.
+    fn current_import(&mut self) -> &mut LstImport {
+        self.lst
+            .imports
+            .last_mut()
+            .expect("current_import() called when no import struct had been encountered.")
+    }
+
     // Reads the raw reader's current value expecting a symbol. Stores the value in
     // `self.lst.current_symbol` so it can be returned if the user requests it.
     fn load_current_symbol(&mut self) -> IonResult<()> {
@@ -340,7 +520,8 @@ impl<C: RawReader> SystemReader<C> {
     fn load_current_int(&mut self) -> IonResult<()> {
         // Note: This method will only be called on integers found inside of local symbol tables.
         //       If an LST has an integer that's too big to fit in an i64, this will fail.
-        self.raw_reader
+        self.lst.current_int = self
+            .raw_reader
             .read_i64()?
             .expect("load_current_int() called at a value that was not an integer.");
         Ok(())
@@ -370,6 +551,13 @@ impl<C: RawReader> SystemReader<C> {
             ProcessingLstImports => {
                 // We're diving deeper into the imports; do nothing.
             }
+            AtLstImport => {
+                // We've stepped into an import struct.
+                self.lst.state = ProcessingLstImport;
+            }
+            ProcessingLstImport => {
+                // We're diving deeper into an import struct; do nothing.
+            }
             AtLstSymbols => {
                 // We've stepped into the `symbols` field of an LST.
                 self.lst.state = ProcessingLstSymbols;
@@ -406,11 +594,23 @@ impl<C: RawReader> SystemReader<C> {
                 // We're stepping out of the local symbol table altogether. Finish processing the
                 // LST instead of skipping its remaining contents.
                 self.finish_reading_current_level()?;
-                self.add_lst_symbols_to_current_symbol_table();
+                self.add_lst_symbols_to_current_symbol_table()?;
                 self.lst.is_append = false;
                 new_lst_state = NotReadingAnLst;
             }
-            ProcessingLstImports | ProcessingLstSymbols | ProcessingLstOpenContent => {
+            ProcessingLstImport => {
+                // We're inside an import struct. Finish reading it before stepping out.
+                self.finish_reading_current_level()?;
+                // If the upcoming call to step_out() will cause us to leave the import struct,
+                // update our state to indicate that we're back in the `imports` list.
+                if self.depth() == 3 {
+                    new_lst_state = ProcessingLstImports;
+                }
+            }
+            ProcessingLstImports
+            | AtLstImport
+            | ProcessingLstSymbols
+            | ProcessingLstOpenContent => {
                 // We're inside one of the LST fields. Finish processing the current level before
                 // stepping out.
                 self.finish_reading_current_level()?;
@@ -427,24 +627,84 @@ impl<C: RawReader> SystemReader<C> {
         Ok(())
     }
 
-    // When the reader steps out of an LST, this method will add the new symbols we've been
-    // buffering in `self.lst.symbols` to the current symbol table.
-    fn add_lst_symbols_to_current_symbol_table(&mut self) {
+    // When the reader steps out of an LST, this method will add the symbols from any imported
+    // shared symbol tables and the new symbols we've been buffering in `self.lst.symbols` to the
+    // current symbol table.
+    fn add_lst_symbols_to_current_symbol_table(&mut self) -> IonResult<()> {
         if !self.lst.is_append {
             // This is not an append. Clear the current symbol table.
             self.symbol_table.reset();
+            self.emit_encoding_event(EncodingEvent::SymbolTableReset);
+        }
+        let initial_len = self.symbol_table.len();
+        // Imported symbols are assigned IDs before the symbols defined in the LST itself.
+        for import in std::mem::take(&mut self.lst.imports) {
+            self.add_imported_symbols(import)?;
         }
-        // TODO: support importing shared symbol tables
         // This for loop consumes the `String` values, clearing `self.lst.symbols`.
         for value in self.lst.symbols.drain(..) {
             if let Some(text) = value {
-                // This symbol has defined text. Add it to the symbol table.
-                self.symbol_table.intern(text);
+                // This symbol has defined text. Add it to the symbol table. Like imported
+                // symbols, each LST symbol is assigned a new ID even if its text is already in
+                // the table.
+                self.symbol_table.add_symbol(text);
             } else {
                 // This symbol was a null or non-string value. Add a placeholder.
                 self.symbol_table.add_placeholder();
             }
         }
+        let symbols_added = self.symbol_table.len() - initial_len;
+        self.emit_encoding_event(EncodingEvent::SymbolTableAppend(symbols_added));
+        Ok(())
+    }
+
+    // Adds the symbols from an imported shared symbol table to the current symbol table. If the
+    // shared symbol table cannot be found in the catalog, the symbol IDs it would have occupied are
+    // added as placeholders with unknown text.
+    // See: https://amzn.github.io/ion-docs/docs/symbols.html#imports
+    fn add_imported_symbols(&mut self, import: LstImport) -> IonResult<()> {
+        let name = match import.name {
+            // Imports without a name (or that name the system symbol table) are ignored.
+            Some(name) if !name.is_empty() && name != "$ion" => name,
+            _ => return Ok(()),
+        };
+        // A missing or invalid version is treated as version 1.
+        let version = match import.version {
+            Some(version) if version >= 1 => u32::try_from(version).unwrap_or(u32::MAX),
+            _ => 1,
+        };
+        let table = self
+            .catalog
+            .as_ref()
+            .and_then(|catalog| catalog.get_table(&name, version));
+        let max_id = match (import.max_id, table) {
+            (Some(max_id), _)
+                if max_id >= 0 && max_id as u64 > self.max_imported_symbols as u64 =>
+            {
+                return decoding_error(format!(
+                    "The import of shared symbol table '{}' version {} has a max_id of {}, which \
+                    exceeds the configured maximum of {}.",
+                    name, version, max_id, self.max_imported_symbols
+                ))
+            }
+            (Some(max_id), _) if max_id >= 0 => max_id as usize,
+            (_, Some(table)) => table.symbols().len(),
+            (_, None) => {
+                return decoding_error(format!(
+                    "Shared symbol table '{}' version {} is not in the catalog and its import \
+                    does not specify a max_id.",
+                    name, version
+                ))
+            }
+        };
+        let symbols = table.map(|table| table.symbols()).unwrap_or(&[]);
+        for index in 0..max_id {
+            match symbols.get(index) {
+                Some(Some(text)) => self.symbol_table.add_symbol(text.clone()),
+                _ => self.symbol_table.add_placeholder(),
+            };
+        }
+        Ok(())
     }
 
     // The SystemReader can skip any user-level value, but cannot skip Local Symbol Tables (LSTs) in
@@ -519,6 +779,7 @@ impl<C: RawReader> SystemReader<C> {
 
         self.lst.state = NotReadingAnLst;
         self.symbol_table.reset();
+        self.emit_encoding_event(EncodingEvent::IonVersion(major, minor));
         Ok(Some(SystemStreamItem::VersionMarker(major, minor)))
     }
 
@@ -548,6 +809,12 @@ impl<C: RawReader> SystemReader<C> {
         &self.symbol_table
     }
 
+    /// Reads the current symbol value and returns its text.
+    ///
+    /// If the symbol's text cannot be resolved, the reader's [SymbolResolutionPolicy] is applied.
+    /// Because a `String` cannot hold a symbol ID, [SymbolResolutionPolicy::ReturnSymbolId] will
+    /// return an error for out-of-range symbol IDs and `None` for symbol IDs with unknown text;
+    /// use [SystemReader::read_resolved_symbol] to get the symbol ID itself.
     pub fn read_symbol(&mut self) -> IonResult<Option<String>> {
         let sid = match self.read_raw_symbol()? {
             Some(RawSymbolToken::Text(text)) => return Ok(Some(text)),
@@ -556,11 +823,42 @@ impl<C: RawReader> SystemReader<C> {
         };
         if let Some(text) = self.symbol_table.text_for(sid) {
             // TODO: SymbolTable should use Rc<str> so this is cheap
-            Ok(Some(text.to_string()))
-        } else if !self.symbol_table.sid_is_valid(sid) {
-            decoding_error(format!("Symbol ID ${} is out of range.", sid))
-        } else {
-            Ok(None)
+            return Ok(Some(text.to_string()));
+        }
+        match self.resolve_unknown_symbol_id(sid)? {
+            RawSymbolToken::Text(placeholder) => Ok(Some(placeholder)),
+            RawSymbolToken::SymbolId(_) if !self.symbol_table.sid_is_valid(sid) => {
+                decoding_error(format!("Symbol ID ${} is out of range.", sid))
+            }
+            RawSymbolToken::SymbolId(_) => Ok(None),
+        }
+    }
+
+    /// Reads the current symbol value, resolving its text if possible. If the symbol's text
+    /// cannot be resolved, the reader's [SymbolResolutionPolicy] determines whether this returns
+    /// an error, the symbol ID, or placeholder text.
+    pub fn read_resolved_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
+        let sid = match self.read_raw_symbol()? {
+            Some(RawSymbolToken::SymbolId(sid)) => sid,
+            other => return Ok(other),
+        };
+        if let Some(text) = self.symbol_table.text_for(sid) {
+            return Ok(Some(RawSymbolToken::Text(text.to_string())));
+        }
+        self.resolve_unknown_symbol_id(sid).map(Some)
+    }
+
+    // Applies the reader's SymbolResolutionPolicy to a symbol ID whose text is not available.
+    fn resolve_unknown_symbol_id(&self, sid: usize) -> IonResult<RawSymbolToken> {
+        match self.symbol_resolution_policy {
+            SymbolResolutionPolicy::Error if !self.symbol_table.sid_is_valid(sid) => {
+                decoding_error(format!("Symbol ID ${} is out of range.", sid))
+            }
+            SymbolResolutionPolicy::Error => {
+                decoding_error(format!("Symbol ID ${} has unknown text.", sid))
+            }
+            SymbolResolutionPolicy::ReturnSymbolId => Ok(RawSymbolToken::SymbolId(sid)),
+            SymbolResolutionPolicy::Placeholder => Ok(RawSymbolToken::Text(format!("${}", sid))),
         }
     }
 
@@ -588,11 +886,24 @@ impl<C: RawReader> SystemReader<C> {
         // The raw reader is inside the `symbols` field of an LST and its value is a string.
         // This means that the system reader has eagerly loaded the string to eventually store
         // its text in the current symbol table. Return a copy of the materialized string value.
-        self.lst.state == ProcessingLstSymbols
+        // The same is true of the `name` field in an import struct.
+        (self.lst.state == ProcessingLstSymbols || self.current_import_field_was_consumed())
             && self.raw_reader.ion_type() == Some(IonType::String)
             && !self.raw_reader.is_null()
     }
 
+    pub fn read_i64(&mut self) -> IonResult<Option<i64>> {
+        if self.current_import_field_was_consumed()
+            && self.raw_reader.ion_type() == Some(IonType::Integer)
+        {
+            // The system reader has already consumed the `version` or `max_id` of an import
+            // struct. Return a copy of the materialized integer value.
+            return Ok(Some(self.lst.current_int));
+        }
+        // Otherwise, delegate to the raw reader
+        self.raw_reader.read_i64()
+    }
+
     pub fn read_string(&mut self) -> IonResult<Option<String>> {
         if self.current_string_was_consumed() {
             return Ok(Some(self.lst.current_string.clone()));
@@ -623,6 +934,104 @@ impl<C: RawReader> SystemReader<C> {
         self.raw_reader.string_bytes_map(f)
     }
 
+    // Advances to the next user-level value at the current depth, skipping over version markers
+    // and symbol tables. Returns false if there are no more values.
+    fn next_user_value(&mut self) -> IonResult<bool> {
+        loop {
+            match self.next()? {
+                Some(SystemStreamItem::Value(_, _)) => return Ok(true),
+                Some(_) => continue,
+                None => return Ok(false),
+            }
+        }
+    }
+
+    // Materializes the current value (including any children) as an OwnedElement, resolving
+    // symbol IDs using the current symbol table.
+    fn read_element(&mut self) -> IonResult<OwnedElement> {
+        let annotations = self
+            .raw_reader
+            .annotations()
+            .iter()
+            .map(|token| self.owned_symbol_token(token))
+            .collect();
+        let value = match (self.ion_type(), self.is_null()) {
+            (Some(ion_type), true) => OwnedValue::Null(ion_type),
+            (Some(IonType::List), false) => {
+                OwnedValue::List(OwnedSequence::new(self.read_child_elements()?))
+            }
+            (Some(IonType::SExpression), false) => {
+                OwnedValue::SExpression(OwnedSequence::new(self.read_child_elements()?))
+            }
+            (Some(IonType::Struct), false) => {
+                self.step_in()?;
+                let mut fields = Vec::new();
+                while self.next_user_value()? {
+                    let field_name = self
+                        .raw_reader
+                        .field_name()
+                        .map(|token| self.owned_symbol_token(token))
+                        .expect("No field name found for a value inside a struct.");
+                    fields.push((field_name, self.read_element()?));
+                }
+                self.step_out()?;
+                OwnedValue::Struct(fields.into_iter().collect())
+            }
+            (Some(IonType::Boolean), false) => {
+                OwnedValue::Boolean(expect_value(self.read_bool()?)?)
+            }
+            (Some(IonType::Integer), false) => {
+                OwnedValue::Integer(AnyInt::I64(expect_value(self.read_i64()?)?))
+            }
+            (Some(IonType::Float), false) => OwnedValue::Float(expect_value(self.read_f64()?)?),
+            (Some(IonType::Decimal), false) => {
+                OwnedValue::Decimal(expect_value(self.read_decimal()?)?)
+            }
+            (Some(IonType::Timestamp), false) => {
+                OwnedValue::Timestamp(expect_value(self.read_timestamp()?)?)
+            }
+            (Some(IonType::String), false) => {
+                OwnedValue::String(expect_value(self.read_string()?)?)
+            }
+            (Some(IonType::Symbol), false) => {
+                let token = expect_value(self.read_raw_symbol()?)?;
+                OwnedValue::Symbol(self.owned_symbol_token(&token))
+            }
+            (Some(IonType::Blob), false) => {
+                OwnedValue::Blob(expect_value(self.read_blob_bytes()?)?)
+            }
+            (Some(IonType::Clob), false) => {
+                OwnedValue::Clob(expect_value(self.read_clob_bytes()?)?)
+            }
+            (Some(IonType::Null), false) | (None, _) => {
+                return illegal_operation("The reader is not positioned over a value.")
+            }
+        };
+        Ok(OwnedElement::new(annotations, value))
+    }
+
+    // Steps into the current container, materializes each of its children, and steps back out.
+    fn read_child_elements(&mut self) -> IonResult<Vec<OwnedElement>> {
+        self.step_in()?;
+        let mut elements = Vec::new();
+        while self.next_user_value()? {
+            elements.push(self.read_element()?);
+        }
+        self.step_out()?;
+        Ok(elements)
+    }
+
+    // Converts a raw symbol token into an OwnedSymbolToken, resolving its text if possible.
+    fn owned_symbol_token(&self, token: &RawSymbolToken) -> OwnedSymbolToken {
+        match token {
+            RawSymbolToken::Text(text) => text_token(text.as_str()),
+            RawSymbolToken::SymbolId(sid) => match self.symbol_table.text_for(*sid) {
+                Some(text) => text_token(text),
+                None => local_sid_token(*sid),
+            },
+        }
+    }
+
     // The SystemReader needs to expose many of the same functions as the Cursor, but only some of
     // those need to be re-defined to allow for system value processing. Any method listed here will
     // be delegated to self.raw_reader directly.
@@ -633,7 +1042,6 @@ impl<C: RawReader> SystemReader<C> {
             pub fn ion_type(&self) -> Option<IonType>;
             pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
             pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
-            pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
             pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
             pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
             pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
@@ -648,6 +1056,104 @@ impl<C: RawReader> SystemReader<C> {
     }
 }
 
+/// Walks the user-level values of two readers in lockstep, returning `true` if both produce
+/// equivalent streams and `false` otherwise. Values are compared by type, value, annotations, and
+/// (within structs) field names; symbol IDs are resolved to text before comparison, so a text
+/// stream and its binary encoding are considered equivalent. Version markers and local symbol
+/// tables are not compared. Struct fields may appear in any order.
+///
+/// Values are compared using the Ion data model's notion of equivalence rather than numeric
+/// equality: decimals must have the same coefficient and exponent (`1.0` and `1.00` are not
+/// equivalent), floats must have the same bits (`0e0` and `-0e0` are not equivalent, but `nan` is
+/// equivalent to `nan`), and timestamps must have the same precision and offset.
+///
+/// Each top-level value is materialized in memory before it is compared. Integers are read with
+/// `read_i64`, so a stream containing an integer that does not fit in an `i64` will produce an
+/// error.
+pub fn equivalent_streams<A: RawReader, B: RawReader>(
+    a: &mut SystemReader<A>,
+    b: &mut SystemReader<B>,
+) -> IonResult<bool> {
+    loop {
+        match (a.next_user_value()?, b.next_user_value()?) {
+            (true, true) => {
+                if !ion_equivalent(&a.read_element()?, &b.read_element()?) {
+                    return Ok(false);
+                }
+            }
+            (false, false) => return Ok(true),
+            _ => return Ok(false),
+        }
+    }
+}
+
+// Returns `true` if `a` and `b` are equivalent according to the Ion data model. Unlike
+// OwnedElement's PartialEq implementation, this distinguishes between decimals with different
+// precisions and treats `nan` as equivalent to itself.
+fn ion_equivalent(a: &OwnedElement, b: &OwnedElement) -> bool {
+    if a.ion_type() != b.ion_type()
+        || a.is_null() != b.is_null()
+        || !a.annotations().eq(b.annotations())
+    {
+        return false;
+    }
+    if a.is_null() {
+        return true;
+    }
+    match a.ion_type() {
+        IonType::Float => match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits(),
+            _ => false,
+        },
+        IonType::Decimal => match (a.as_decimal(), b.as_decimal()) {
+            (Some(a), Some(b)) => a.coefficient == b.coefficient && a.exponent == b.exponent,
+            _ => false,
+        },
+        IonType::List | IonType::SExpression => match (a.as_sequence(), b.as_sequence()) {
+            (Some(a), Some(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| ion_equivalent(a, b))
+            }
+            _ => false,
+        },
+        IonType::Struct => match (a.as_struct(), b.as_struct()) {
+            (Some(a), Some(b)) => structs_equivalent(a, b),
+            _ => false,
+        },
+        // The remaining types' PartialEq implementations already match the data model.
+        _ => a == b,
+    }
+}
+
+// Returns `true` if each field in `a` can be paired with a distinct field in `b` that has the same
+// name and an equivalent value, and vice versa. Field order is not significant.
+fn structs_equivalent(a: &OwnedStruct, b: &OwnedStruct) -> bool {
+    let b_fields: Vec<_> = b.iter().collect();
+    let mut is_matched = vec![false; b_fields.len()];
+    let mut a_len = 0;
+    for (name, value) in a.iter() {
+        a_len += 1;
+        let matching_field = b_fields
+            .iter()
+            .enumerate()
+            .position(|(index, (b_name, b_value))| {
+                !is_matched[index] && name == *b_name && ion_equivalent(value, b_value)
+            });
+        match matching_field {
+            Some(index) => is_matched[index] = true,
+            None => return false,
+        }
+    }
+    a_len == b_fields.len()
+}
+
+// Unwraps a value that the reader has already reported to be present.
+fn expect_value<V>(value: Option<V>) -> IonResult<V> {
+    match value {
+        Some(value) => Ok(value),
+        None => illegal_operation("The reader reported a non-null value but could not read it."),
+    }
+}
+
 /// Functionality that is only available if the data source we're reading from is in-memory, like
 /// a Vec<u8> or &[u8].
 impl<T: AsRef<[u8]>> SystemReader<RawBinaryReader<io::Cursor<T>>> {
@@ -682,12 +1188,204 @@ impl<T: AsRef<[u8]>> SystemReader<RawBinaryReader<io::Cursor<T>>> {
 mod tests {
     use super::*;
     use crate::text::raw_text_reader::RawTextReader;
+    use crate::SharedSymbolTable;
+    use rstest::*;
+    use std::sync::{Arc, Mutex};
 
     fn system_reader_for(ion: &str) -> SystemReader<RawTextReader<&str>> {
         let raw_reader = RawTextReader::new(ion);
         SystemReader::new(raw_reader)
     }
 
+    // A catalog backed by a list of shared symbol tables.
+    struct TestCatalog {
+        tables: Vec<SharedSymbolTable>,
+    }
+
+    impl Catalog for TestCatalog {
+        fn get_table(&self, name: &str, version: u32) -> Option<&SharedSymbolTable> {
+            self.tables
+                .iter()
+                .find(|table| table.name() == name && table.version() == version)
+        }
+    }
+
+    fn system_reader_with_catalog_for(ion: &str) -> SystemReader<RawTextReader<&str>> {
+        let symbols = |texts: &[&str]| texts.iter().map(|t| Some(t.to_string())).collect();
+        let catalog = TestCatalog {
+            tables: vec![
+                SharedSymbolTable::new("fruit", 1, symbols(&["apple", "banana", "cherry"])),
+                SharedSymbolTable::new("fruit", 2, symbols(&["durian", "elderberry"])),
+                SharedSymbolTable::new("veggies", 1, symbols(&["kale"])),
+            ],
+        };
+        system_reader_for(ion).with_catalog(Box::new(catalog))
+    }
+
+    // Advances the reader to the next user value (which must be a symbol) and returns its text.
+    fn next_symbol_text(
+        reader: &mut SystemReader<RawTextReader<&str>>,
+    ) -> IonResult<Option<String>> {
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::Value(IonType::Symbol, false))
+        );
+        reader.read_symbol()
+    }
+
+    #[test]
+    fn shared_symbol_table_imports() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+            $ion_symbol_table::{
+                imports: [
+                    {name: "fruit", version: 1, max_id: 2},
+                    {name: "missing", version: 1, max_id: 2},
+                    {name: "fruit", version: 2},
+                    {name: "veggies", max_id: 3},
+                ],
+                symbols: ["local"],
+            }
+            $10 $11 $12 $13 $14 $15 $16 $17 $18 $19
+          "#,
+        );
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::SymbolTableData(IonType::Struct, false))
+        );
+        // fruit v1, limited to 2 symbols by max_id
+        assert_eq!(next_symbol_text(&mut reader)?, Some("apple".to_string()));
+        assert_eq!(next_symbol_text(&mut reader)?, Some("banana".to_string()));
+        // missing is not in the catalog; its 2 symbols have unknown text
+        assert_eq!(next_symbol_text(&mut reader)?, None);
+        assert_eq!(next_symbol_text(&mut reader)?, None);
+        // fruit v2, no max_id, so all of its symbols are imported
+        assert_eq!(next_symbol_text(&mut reader)?, Some("durian".to_string()));
+        assert_eq!(
+            next_symbol_text(&mut reader)?,
+            Some("elderberry".to_string())
+        );
+        // veggies (version defaults to 1), padded to a max_id of 3
+        assert_eq!(next_symbol_text(&mut reader)?, Some("kale".to_string()));
+        assert_eq!(next_symbol_text(&mut reader)?, None);
+        assert_eq!(next_symbol_text(&mut reader)?, None);
+        // The LST's own symbols come after the imports
+        assert_eq!(next_symbol_text(&mut reader)?, Some("local".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_symbols_are_assigned_their_own_ids() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 1, max_id: 1}],
+                // "name" is a system symbol and "apple" was imported from fruit
+                symbols: ["name", "apple", "local"],
+            }
+            $10 $11 $12 $13
+          "#,
+        );
+        reader.next()?;
+        assert_eq!(next_symbol_text(&mut reader)?, Some("apple".to_string()));
+        assert_eq!(next_symbol_text(&mut reader)?, Some("name".to_string()));
+        assert_eq!(next_symbol_text(&mut reader)?, Some("apple".to_string()));
+        assert_eq!(next_symbol_text(&mut reader)?, Some("local".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_imports_without_catalog() -> IonResult<()> {
+        let mut reader = system_reader_for(
+            r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 1, max_id: 1}],
+                symbols: ["local"],
+            }
+            $10 $11
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 1}],
+            }
+          "#,
+        );
+        reader.next()?;
+        assert_eq!(next_symbol_text(&mut reader)?, None);
+        assert_eq!(next_symbol_text(&mut reader)?, Some("local".to_string()));
+        // Without a max_id, there's no way to know how many symbol IDs the import occupies.
+        assert!(reader.next().and_then(|_| reader.next()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn import_max_id_limit() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 1, max_id: 3}],
+            }
+            $12
+            $ion_symbol_table::{
+                imports: [{name: "missing", version: 1, max_id: 9999999999999}],
+            }
+            $10
+          "#;
+        let mut reader = system_reader_with_catalog_for(text).with_max_imported_symbols(3);
+        reader.next()?;
+        assert_eq!(next_symbol_text(&mut reader)?, Some("cherry".to_string()));
+        // The second import would add far more placeholders than the limit allows.
+        assert!(reader.next().and_then(|_| reader.next()).is_err());
+
+        let mut reader = system_reader_with_catalog_for(text).with_max_imported_symbols(2);
+        assert!(reader.next().and_then(|_| reader.next()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn manually_step_through_lst_imports() -> IonResult<()> {
+        let mut reader = system_reader_with_catalog_for(
+            r#"
+            $ion_symbol_table::{
+                imports: [{name: "fruit", version: 1, max_id: 3}],
+            }
+            $10 $11 $12
+          "#,
+        );
+        reader.next()?;
+        reader.step_in()?;
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::SymbolTableData(IonType::List, false))
+        );
+        assert_eq!(reader.field_name(), Some("imports"));
+        reader.step_in()?;
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::SymbolTableData(IonType::Struct, false))
+        );
+        reader.step_in()?;
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::SymbolTableData(IonType::String, false))
+        );
+        assert_eq!(reader.field_name(), Some("name"));
+        assert_eq!(reader.read_string()?, Some("fruit".to_string()));
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::SymbolTableData(IonType::Integer, false))
+        );
+        assert_eq!(reader.field_name(), Some("version"));
+        assert_eq!(reader.read_i64()?, Some(1));
+        // Step out of the import struct without visiting `max_id`; it should still be processed.
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        reader.step_out()?;
+
+        assert_eq!(next_symbol_text(&mut reader)?, Some("apple".to_string()));
+        assert_eq!(next_symbol_text(&mut reader)?, Some("banana".to_string()));
+        assert_eq!(next_symbol_text(&mut reader)?, Some("cherry".to_string()));
+        Ok(())
+    }
+
     #[test]
     fn basic_symbol_table() -> IonResult<()> {
         // The stream contains a local symbol table that is not an append.
@@ -726,6 +1424,138 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn encoding_events() -> IonResult<()> {
+        let mut reader = system_reader_for(
+            r#"
+            $ion_1_0
+            $ion_symbol_table::{
+                symbols: ["foo", "bar"],
+            }
+            foo
+            $ion_symbol_table::{
+                imports: $ion_symbol_table,
+                symbols: ["baz"],
+            }
+            baz
+          "#,
+        );
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&events);
+        reader.on_encoding_event(move |event| log.lock().unwrap().push(event));
+        while reader.next()?.is_some() {}
+        assert_eq!(
+            events.lock().unwrap().as_slice(),
+            &[
+                EncodingEvent::IonVersion(1, 0),
+                EncodingEvent::SymbolTableReset,
+                EncodingEvent::SymbolTableAppend(2),
+                EncodingEvent::SymbolTableAppend(1),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn system_reader_is_send() {
+        // Catalogs, encoding event handlers, and the text reader's symbol cache and tee must not
+        // prevent a reader from moving across threads.
+        fn assert_send<T: Send>() {}
+        assert_send::<SystemReader<RawBinaryReader<io::Cursor<Vec<u8>>>>>();
+        assert_send::<SystemReader<RawTextReader<&str>>>();
+    }
+
+    #[test]
+    fn symbol_resolution_policy_error() -> IonResult<()> {
+        let mut reader = system_reader_for("$999 $999")
+            .with_symbol_resolution_policy(SymbolResolutionPolicy::Error);
+        reader.next()?;
+        assert!(reader.read_symbol().is_err());
+        reader.next()?;
+        assert!(reader.read_resolved_symbol().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_resolution_policy_return_symbol_id() -> IonResult<()> {
+        // This is the default policy
+        let mut reader = system_reader_for("$999 $999 $4");
+        reader.next()?;
+        assert!(reader.read_symbol().is_err());
+        reader.next()?;
+        assert_eq!(
+            reader.read_resolved_symbol()?,
+            Some(RawSymbolToken::SymbolId(999))
+        );
+        // Symbol IDs with known text are resolved regardless of the policy
+        reader.next()?;
+        assert_eq!(
+            reader.read_resolved_symbol()?,
+            Some(RawSymbolToken::Text("name".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_resolution_policy_placeholder() -> IonResult<()> {
+        let mut reader = system_reader_for("$999 $999")
+            .with_symbol_resolution_policy(SymbolResolutionPolicy::Placeholder);
+        reader.next()?;
+        assert_eq!(reader.read_symbol()?, Some("$999".to_string()));
+        reader.next()?;
+        assert_eq!(
+            reader.read_resolved_symbol()?,
+            Some(RawSymbolToken::Text("$999".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn struct_field_names_with_symbol_ids() -> IonResult<()> {
+        let mut reader = system_reader_for(
+            r#"
+            {$10: 1, $4: 2}
+            $ion_symbol_table::{
+                symbols: ["foo"],
+            }
+            {$10: 3, bar: 4}
+          "#,
+        );
+        // Before the LST, $10 is out of range and its text is unknown
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::Value(IonType::Struct, false))
+        );
+        reader.step_in()?;
+        reader.next()?;
+        assert_eq!(reader.field_name(), None);
+        assert_eq!(
+            reader.raw_field_name_token(),
+            Some(&RawSymbolToken::SymbolId(10))
+        );
+        // Symbol IDs from the system symbol table are always resolved
+        reader.next()?;
+        assert_eq!(reader.field_name(), Some("name"));
+        reader.step_out()?;
+
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::SymbolTableData(IonType::Struct, false))
+        );
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::Value(IonType::Struct, false))
+        );
+        reader.step_in()?;
+        reader.next()?;
+        assert_eq!(reader.field_name(), Some("foo"));
+        assert_eq!(reader.read_i64()?, Some(3));
+        reader.next()?;
+        assert_eq!(reader.field_name(), Some("bar"));
+        reader.step_out()?;
+        Ok(())
+    }
+
     #[test]
     fn symbol_table_append() -> IonResult<()> {
         // The stream contains multiple LST appends
@@ -925,4 +1755,81 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn equivalent_text_streams() -> IonResult<()> {
+        let a = r#"
+            $ion_1_0
+            foo::{name: "widget", sizes: [1, 2, 16], tags: (a b c)}
+            2021-03-04T05:06:07Z
+            null.list
+        "#;
+        let b = r#"
+            $ion_symbol_table::{symbols: ["foo", "sizes", "tags"]}
+            $10::{
+                $11: [1,2,0x10],
+                // Field order is not significant
+                $4: '''wid''' '''get''',
+                $12: ('a' b c),
+            } 2021-03-04T05:06:07+00:00 null.list
+        "#;
+        assert!(equivalent_streams(
+            &mut system_reader_for(a),
+            &mut system_reader_for(b)
+        )?);
+        Ok(())
+    }
+
+    #[test]
+    fn non_equivalent_text_streams() -> IonResult<()> {
+        let differ = |a, b| -> IonResult<bool> {
+            Ok(!equivalent_streams(
+                &mut system_reader_for(a),
+                &mut system_reader_for(b),
+            )?)
+        };
+        // Different values
+        assert!(differ("1 2 3", "1 2 4")?);
+        // Different types
+        assert!(differ("foo", "\"foo\"")?);
+        // Different annotations
+        assert!(differ("a::1", "b::1")?);
+        // Different field names
+        assert!(differ("{a: 1}", "{b: 1}")?);
+        // One stream is longer than the other
+        assert!(differ("1 2", "1 2 3")?);
+        assert!(differ("[1, 2]", "[1, 2, 3]")?);
+        // Decimals with different precisions
+        assert!(differ("1.0", "1.00")?);
+        assert!(differ("[1.0]", "[1.00]")?);
+        assert!(differ("0.", "-0.")?);
+        // Floats with different signs
+        assert!(differ("0e0", "-0e0")?);
+        // Repeated fields with different values
+        assert!(differ("{a: 1, a: 1}", "{a: 1, a: 2}")?);
+        assert!(differ("{a: 1, a: 1}", "{a: 1}")?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1.0", "10d-1")]
+    #[case("nan", "nan")]
+    #[case("[nan, 1.5e0]", "[nan, 15e-1]")]
+    #[case("{a: 1, a: 2}", "{a: 2, a: 1}")]
+    #[case("{a: 1.0, b: nan}", "{b: nan, a: 1.0}")]
+    fn equivalent_numeric_values(#[case] a: &str, #[case] b: &str) -> IonResult<()> {
+        assert!(equivalent_streams(
+            &mut system_reader_for(a),
+            &mut system_reader_for(b)
+        )?);
+        Ok(())
+    }
+
+    #[test]
+    fn equivalent_streams_rejects_big_integers() {
+        let big = "9223372036854775808";
+        assert!(
+            equivalent_streams(&mut system_reader_for(big), &mut system_reader_for(big)).is_err()
+        );
+    }
 }
@@ -0,0 +1,365 @@
+use std::collections::VecDeque;
+use std::io;
+
+use delegate::delegate;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::raw_reader::{RawReader, RawStreamItem};
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{illegal_operation, IonError, IonResult};
+use crate::text::delimiter_scanner::DelimiterScanner;
+use crate::text::raw_text_reader::{RawTextReader, RawTextReaderBuilder};
+use crate::text::text_data_source::LineIteratorSource;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::IonType;
+
+// Lines of text that have been read from the async source and are ready to be parsed. When it runs
+// out of lines before the async source has been exhausted, it reports a `WouldBlock` error instead
+// of the end of the stream.
+struct PendingLines {
+    lines: VecDeque<String>,
+    // Whether the async source has been exhausted.
+    is_complete: bool,
+}
+
+impl Iterator for PendingLines {
+    type Item = IonResult<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.lines.pop_front() {
+            Some(line) => Some(Ok(line)),
+            None if self.is_complete => None,
+            None => Some(Err(IonError::IoError {
+                source: io::Error::from(io::ErrorKind::WouldBlock),
+            })),
+        }
+    }
+}
+
+/// A text reader that pulls its input from a tokio [AsyncBufRead]. When the reader needs more
+/// text to parse the next value, [next](Self::next) awaits it rather than blocking.
+///
+/// `AsyncTextReader` uses a [RawTextReader] to do its parsing. Text is read from the source one
+/// line at a time and is only handed to the `RawTextReader` once every container that has been
+/// opened has also been closed. As a result, each top-level container is buffered in its entirety
+/// before it is returned, and reading the values inside it never needs to wait. Text is only read
+/// from the source when the reader needs more of it, so a slow consumer will not cause unbounded
+/// buffering.
+///
+/// `AsyncTextReader` is `Send` whenever its source is, so it can be held across an `.await` in a
+/// task that is spawned onto a multi-threaded runtime.
+///
+/// This type is only available when the `async` feature is enabled.
+pub struct AsyncTextReader<R> {
+    source: R,
+    // The reader's source owns the lines that are waiting to be parsed; see `load_next_lines()`.
+    reader: RawTextReader<LineIteratorSource<PendingLines>>,
+    // Tracks the containers that are left open by the lines in `incomplete_lines`.
+    scanner: DelimiterScanner,
+    // Lines that have been read from the source but that end inside a container, string, or
+    // comment.
+    incomplete_lines: Vec<String>,
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncTextReader<R> {
+    pub fn new(source: R) -> AsyncTextReader<R> {
+        AsyncTextReader::from_builder(RawTextReaderBuilder::new(), source)
+    }
+
+    /// Constructs an `AsyncTextReader` whose underlying [RawTextReader] uses the configuration in
+    /// `builder`.
+    pub fn from_builder(builder: RawTextReaderBuilder, source: R) -> AsyncTextReader<R> {
+        let reader = builder.build(LineIteratorSource::new(PendingLines {
+            lines: VecDeque::new(),
+            is_complete: false,
+        }));
+        AsyncTextReader {
+            source,
+            reader,
+            scanner: DelimiterScanner::new(0),
+            incomplete_lines: Vec::new(),
+        }
+    }
+
+    /// Advances the reader to the next value or IVM at the current depth, reading more text from
+    /// the source if needed. Behaves like [RawReader::next].
+    pub async fn next(&mut self) -> IonResult<Option<RawStreamItem>> {
+        let depth = self.reader.depth();
+        loop {
+            match self.reader.next() {
+                // The RawTextReader ran out of text before it could determine what the next value
+                // was. Top-level containers are buffered in their entirety, so skipping one never
+                // runs out of text; nothing was consumed, so it is safe to try again once more
+                // text is ready.
+                Err(IonError::IoError { source }) if source.kind() == io::ErrorKind::WouldBlock => {
+                    if self.reader.depth() != depth {
+                        // The reader stepped into a container and could not step back out.
+                        // Retrying would return the values inside the container.
+                        return illegal_operation(
+                            "The reader ran out of text while skipping a container.",
+                        );
+                    }
+                    self.load_next_lines().await?
+                }
+                result => return result,
+            }
+        }
+    }
+
+    // Reads lines from the source until the text that has been read does not leave any containers,
+    // strings, or comments open, then makes those lines available to the RawTextReader.
+    async fn load_next_lines(&mut self) -> IonResult<()> {
+        loop {
+            let mut line = String::new();
+            if self.source.read_line(&mut line).await? == 0 {
+                // The source is exhausted. Any incomplete lines are handed to the RawTextReader,
+                // which will report the error.
+                let pending = self.reader.source_mut().lines_mut();
+                pending.lines.extend(self.incomplete_lines.drain(..));
+                pending.is_complete = true;
+                return Ok(());
+            }
+            let bytes = line.as_bytes();
+            let mut position = 0;
+            while position < bytes.len() {
+                position += self.scanner.scan_token(&bytes[position..]);
+            }
+            // LineIteratorSource adds a newline to the end of each line.
+            if line.ends_with('\n') {
+                line.pop();
+            }
+            self.incomplete_lines.push(line);
+            if self.scanner.is_at_top_level() {
+                let pending = self.reader.source_mut().lines_mut();
+                pending.lines.extend(self.incomplete_lines.drain(..));
+                return Ok(());
+            }
+        }
+    }
+
+    delegate! {
+        to self.reader {
+            pub fn ion_type(&self) -> Option<IonType>;
+            pub fn is_null(&self) -> bool;
+            pub fn annotations(&self) -> &[RawSymbolToken];
+            pub fn field_name(&self) -> Option<&RawSymbolToken>;
+            pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
+            pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
+            pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
+            pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
+            pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
+            pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+            pub fn read_string(&mut self) -> IonResult<Option<String>>;
+            pub fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>>;
+            pub fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            pub fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            pub fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+            pub fn step_in(&mut self) -> IonResult<()>;
+            pub fn step_out(&mut self) -> IonResult<()>;
+            pub fn depth(&self) -> usize;
+        }
+    }
+}
+
+#[cfg(test)]
+mod async_text_reader_tests {
+    use tokio::io::{AsyncWriteExt, BufReader, DuplexStream};
+
+    use super::AsyncTextReader;
+    use crate::raw_reader::{RawReader, RawStreamItem};
+    use crate::raw_symbol_token::text_token;
+    use crate::result::IonResult;
+    use crate::text::raw_text_reader::RawTextReaderBuilder;
+    use crate::IonType;
+
+    #[tokio::test]
+    async fn test_read_chunks() -> IonResult<()> {
+        // A small buffer forces the reader to receive the text in several partial reads.
+        let (mut writer, reader) = tokio::io::duplex(8);
+        let writer_task = tokio::spawn(async move {
+            let chunks = [
+                "$ion_1_0 {a: [1,",
+                " 2], b: '''multi\n",
+                "line'''}\n",
+                "foo::\"bar\" 3",
+            ];
+            for chunk in chunks {
+                writer.write_all(chunk.as_bytes()).await.unwrap();
+                tokio::task::yield_now().await;
+            }
+            // Dropping the writer ends the stream.
+        });
+
+        let mut reader = AsyncTextReader::new(BufReader::new(reader));
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::VersionMarker(1, 0))
+        );
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::Value(IonType::Struct, false))
+        );
+        reader.step_in()?;
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::Value(IonType::List, false))
+        );
+        assert_eq!(reader.field_name(), Some(&text_token("a")));
+        reader.step_in()?;
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64()?, Some(1));
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.next().await?, None);
+        reader.step_out()?;
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::Value(IonType::String, false))
+        );
+        assert_eq!(reader.field_name(), Some(&text_token("b")));
+        assert_eq!(reader.read_string()?, Some("multi\nline".to_string()));
+        assert_eq!(reader.next().await?, None);
+        reader.step_out()?;
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::Value(IonType::String, false))
+        );
+        assert_eq!(reader.annotations(), &[text_token("foo")]);
+        assert_eq!(reader.read_string()?, Some("bar".to_string()));
+        assert_eq!(
+            reader.next().await?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64()?, Some(3));
+        assert_eq!(reader.next().await?, None);
+
+        writer_task.await.unwrap();
+        Ok(())
+    }
+
+    // Reads all of `text`, stepping into containers only if `step_in` is true. Returns the depth
+    // and type of each value along with the value itself if it is an integer.
+    async fn read_all_async(
+        text: &str,
+        validate: bool,
+        step_in: bool,
+    ) -> IonResult<Vec<(usize, IonType, Option<i64>)>> {
+        let builder = RawTextReaderBuilder::new().with_validate_skipped_containers(validate);
+        let mut reader = AsyncTextReader::from_builder(builder, text.as_bytes());
+        let mut values = Vec::new();
+        loop {
+            match reader.next().await? {
+                Some(RawStreamItem::Value(ion_type, is_null)) => {
+                    values.push((reader.depth(), ion_type, reader.read_i64()?));
+                    if step_in && ion_type.is_container() && !is_null {
+                        reader.step_in()?;
+                    }
+                }
+                Some(RawStreamItem::VersionMarker(_, _)) => {}
+                None if reader.depth() > 0 => reader.step_out()?,
+                None => return Ok(values),
+            }
+        }
+    }
+
+    // The synchronous equivalent of `read_all_async`.
+    fn read_all_sync(
+        text: &str,
+        validate: bool,
+        step_in: bool,
+    ) -> IonResult<Vec<(usize, IonType, Option<i64>)>> {
+        let mut reader = RawTextReaderBuilder::new()
+            .with_validate_skipped_containers(validate)
+            .build(text);
+        let mut values = Vec::new();
+        loop {
+            match reader.next()? {
+                Some(RawStreamItem::Value(ion_type, is_null)) => {
+                    values.push((reader.depth(), ion_type, reader.read_i64()?));
+                    if step_in && ion_type.is_container() && !is_null {
+                        reader.step_in()?;
+                    }
+                }
+                Some(RawStreamItem::VersionMarker(_, _)) => {}
+                None if reader.depth() > 0 => reader.step_out()?,
+                None => return Ok(values),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_matches_sync_reader() -> IonResult<()> {
+        let inputs = [
+            // Containers that end a line
+            "[1, 2]\n7\n",
+            "[1, 2]",
+            "{a:1}\n7\n",
+            "(1 2)\n7\n",
+            "[1, {a: (2)}]\n[3]\n7\n",
+            "[{{aGVsbG8=}}]\n7\n",
+            "[1]\n\n\n7\n\n",
+            // Comments after a container
+            "{a:1}\n/* x\n */ 7\n",
+            "[1] /* x\n\n y */\n7\n",
+            "[1] // x\n7\n",
+            // Containers that span lines
+            "[1,\n2,\n]\n7",
+            "[1, /* ]\n */ 2]\n7\n",
+            "['''a\n]''', 2]\n7\n",
+        ];
+        for text in inputs {
+            for validate in [true, false] {
+                for step_in in [true, false] {
+                    let expected = read_all_sync(text, validate, step_in)?;
+                    let actual = read_all_async(text, validate, step_in).await?;
+                    assert_eq!(
+                        actual, expected,
+                        "text: {:?}, validate: {}, step_in: {}",
+                        text, validate, step_in
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawned_reader() -> IonResult<()> {
+        fn assert_send<T: Send>() {}
+        assert_send::<AsyncTextReader<BufReader<DuplexStream>>>();
+
+        // The reader is held across `.await`s inside a spawned task, which requires it to be Send.
+        let (mut writer, source) = tokio::io::duplex(8);
+        let reader_task = tokio::spawn(async move {
+            let mut reader = AsyncTextReader::new(BufReader::new(source));
+            let mut values = Vec::new();
+            while reader.next().await?.is_some() {
+                values.push(reader.read_i64()?);
+            }
+            IonResult::Ok(values)
+        });
+        writer.write_all(b"1 2\n3").await?;
+        drop(writer);
+        assert_eq!(reader_task.await.unwrap()?, vec![Some(1), Some(2), Some(3)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unterminated_container() {
+        let mut reader = AsyncTextReader::new("[1, 2".as_bytes());
+        assert_eq!(
+            reader.next().await.unwrap(),
+            Some(RawStreamItem::Value(IonType::List, false))
+        );
+        reader.step_in().unwrap();
+        assert!(reader.next().await.is_ok());
+        assert!(reader.next().await.is_err());
+    }
+}
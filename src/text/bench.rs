@@ -0,0 +1,86 @@
+use std::time::{Duration, Instant};
+
+use crate::raw_reader::{RawReader, RawStreamItem};
+use crate::result::IonResult;
+use crate::text::raw_text_reader::RawTextReader;
+
+/// Measurements taken by [parse_throughput].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseMetrics {
+    /// The number of bytes of Ion text that were read.
+    pub bytes_read: usize,
+    /// The number of values that were read, including containers and the values inside them.
+    /// Ion version markers are not counted.
+    pub values_read: usize,
+    /// How long it took to read the input.
+    pub elapsed: Duration,
+    /// `bytes_read` divided by `elapsed`, in seconds.
+    pub bytes_per_second: f64,
+    /// `values_read` divided by `elapsed`, in seconds.
+    pub values_per_second: f64,
+}
+
+/// Reads the provided Ion text in its entirety with a [RawTextReader], stepping into every
+/// container along the way, and reports how quickly it was read. The reader fully parses each
+/// scalar value (allocating for strings, symbols, and lobs), but the values are discarded rather
+/// than read out of the reader, so the time it would take to convert them is not measured.
+/// Returns the first error encountered if the text is malformed.
+///
+/// This function is only available when the `bench` feature is enabled.
+pub fn parse_throughput(input: &str) -> IonResult<ParseMetrics> {
+    let start = Instant::now();
+    let mut reader = RawTextReader::new(input);
+    let mut values_read = 0;
+    loop {
+        match reader.next()? {
+            Some(RawStreamItem::Value(ion_type, is_null)) => {
+                values_read += 1;
+                if ion_type.is_container() && !is_null {
+                    reader.step_in()?;
+                }
+            }
+            Some(RawStreamItem::VersionMarker(_, _)) => {}
+            None if reader.depth() > 0 => reader.step_out()?,
+            None => break,
+        }
+    }
+    let elapsed = start.elapsed();
+    // Very small inputs can be read faster than the clock's resolution.
+    let seconds = elapsed.as_secs_f64().max(1e-9);
+    Ok(ParseMetrics {
+        bytes_read: input.len(),
+        values_read,
+        elapsed,
+        bytes_per_second: input.len() as f64 / seconds,
+        values_per_second: values_read as f64 / seconds,
+    })
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::parse_throughput;
+
+    #[test]
+    fn test_parse_throughput() {
+        let ion_data = r#"
+            $ion_1_0
+            foo::{
+                bar: [1, 2.5, 3e0, "four", five, (6 + 7)],
+                empty: {},
+                nulls: [null, null.list],
+            }
+            42
+        "#;
+        let metrics = parse_throughput(ion_data).unwrap();
+        assert_eq!(metrics.bytes_read, ion_data.len());
+        // The struct, its 3 fields, 6 list values, 3 s-expression values, 2 nulls, and 42
+        assert_eq!(metrics.values_read, 16);
+        assert!(metrics.bytes_per_second > 0.0);
+        assert!(metrics.values_per_second > 0.0);
+    }
+
+    #[test]
+    fn test_parse_throughput_invalid_document() {
+        assert!(parse_throughput("{foo: [1, 2, 3}").is_err());
+    }
+}
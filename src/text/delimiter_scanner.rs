@@ -0,0 +1,123 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScanState {
+    Values,
+    ShortString,
+    LongString,
+    QuotedSymbol,
+    LineComment,
+    BlockComment,
+}
+
+use ScanState::*;
+
+/// Tracks how deeply nested in containers a stream of Ion text is as it is scanned one token at a
+/// time. Rather than parsing values, the scanner only looks for opening and closing container
+/// delimiters. Delimiters that appear inside strings, quoted symbols, lobs, and comments are
+/// ignored.
+#[derive(Clone, Debug)]
+pub(crate) struct DelimiterScanner {
+    state: ScanState,
+    // The number of containers we're currently inside.
+    depth: usize,
+    // Whether we're between the `{{` and `}}` of a blob or clob.
+    is_in_lob: bool,
+}
+
+impl DelimiterScanner {
+    /// Constructs a scanner that begins inside `depth` containers.
+    pub fn new(depth: usize) -> DelimiterScanner {
+        DelimiterScanner {
+            state: Values,
+            depth,
+            is_in_lob: false,
+        }
+    }
+
+    /// Returns the number of containers that the scanned text has left open.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns `true` if the scanner is not inside a container, string, quoted symbol, lob, or
+    /// comment.
+    pub fn is_at_top_level(&self) -> bool {
+        self.depth == 0 && self.state == Values && !self.is_in_lob
+    }
+
+    /// Returns the number of bytes following `byte` that [scan_token](Self::scan_token) needs to
+    /// see to recognize the token that begins with `byte`. A closing delimiter never needs any,
+    /// so a container's end can be found without reading past it.
+    pub fn lookahead(&self, byte: u8) -> usize {
+        match (self.state, byte) {
+            (Values, b'\'') | (LongString, b'\'') => 2,
+            (Values, b'/') if !self.is_in_lob => 1,
+            (Values, b'{') => 1,
+            (Values, b'}') if self.is_in_lob => 1,
+            (ShortString, b'\\') | (QuotedSymbol, b'\\') | (LongString, b'\\') => 1,
+            (BlockComment, b'*') => 1,
+            _ => 0,
+        }
+    }
+
+    /// Scans the token that begins at `text[0]`, returning the number of bytes it occupies. Some
+    /// tokens (e.g. `'''`, `//`) are recognized by looking at the next two bytes, so the caller
+    /// should make sure that the number of bytes reported by [lookahead](Self::lookahead) are
+    /// included in `text` if they are available. `text` must not be
+    /// empty.
+    pub fn scan_token(&mut self, text: &[u8]) -> usize {
+        let byte = text[0];
+        let next = text.get(1).copied();
+        let next_next = text.get(2).copied();
+        let is_long_quote = byte == b'\'' && next == Some(b'\'') && next_next == Some(b'\'');
+        // The number of bytes the current token occupies.
+        let mut width = 1;
+        match self.state {
+            Values => match byte {
+                b'"' => self.state = ShortString,
+                b'\'' if is_long_quote => {
+                    self.state = LongString;
+                    width = 3;
+                }
+                b'\'' => self.state = QuotedSymbol,
+                b'/' if !self.is_in_lob && next == Some(b'/') => {
+                    self.state = LineComment;
+                    width = 2;
+                }
+                b'/' if !self.is_in_lob && next == Some(b'*') => {
+                    self.state = BlockComment;
+                    width = 2;
+                }
+                b'{' if next == Some(b'{') => {
+                    self.is_in_lob = true;
+                    width = 2;
+                }
+                b'}' if self.is_in_lob && next == Some(b'}') => {
+                    self.is_in_lob = false;
+                    width = 2;
+                }
+                b'[' | b'(' | b'{' => self.depth += 1,
+                // A stray closing delimiter is left for the parser to report.
+                b']' | b')' | b'}' => self.depth = self.depth.saturating_sub(1),
+                _ => {}
+            },
+            ShortString | QuotedSymbol | LongString => match byte {
+                // Skip over the escaped character, whatever it is.
+                b'\\' => width = 2,
+                b'"' if self.state == ShortString => self.state = Values,
+                b'\'' if self.state == LongString && is_long_quote => {
+                    self.state = Values;
+                    width = 3;
+                }
+                b'\'' if self.state == QuotedSymbol => self.state = Values,
+                _ => {}
+            },
+            LineComment if byte == b'\n' => self.state = Values,
+            BlockComment if byte == b'*' && next == Some(b'/') => {
+                self.state = Values;
+                width = 2;
+            }
+            LineComment | BlockComment => {}
+        }
+        width
+    }
+}
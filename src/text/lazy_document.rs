@@ -0,0 +1,137 @@
+use std::cell::Cell;
+
+use crate::raw_reader::{RawReader, RawStreamItem};
+use crate::result::IonResult;
+use crate::text::raw_text_reader::{RawTextReader, RawTextReaderBuilder};
+use crate::value::owned::OwnedElement;
+
+/// An Ion text document whose top-level values are located when the document is loaded but
+/// are only materialized when they are requested. See [load_lazy](crate::text::load_lazy).
+///
+/// Like [RawTextReader], a LazyDocument does not interpret symbol tables; symbol IDs in the
+/// materialized values are left unresolved.
+pub struct LazyDocument<'a> {
+    text: &'a str,
+    // The offset at which each top-level value's text begins, including any leading whitespace,
+    // comments, or annotations. Each value's text ends where the next value's text begins.
+    offsets: Vec<usize>,
+    // The number of values that have been materialized by `get`.
+    values_parsed: Cell<usize>,
+}
+
+impl<'a> LazyDocument<'a> {
+    /// Indexes the top-level values in `text`. Containers are skipped over by scanning for their
+    /// closing delimiter; the values inside of them are not parsed until they are requested.
+    pub(crate) fn new(text: &'a str) -> IonResult<LazyDocument<'a>> {
+        let mut reader = RawTextReaderBuilder::new()
+            .with_validate_skipped_containers(false)
+            .build(text);
+        let mut offsets = Vec::new();
+        while let Some(item) = reader.next()? {
+            if let RawStreamItem::Value(_, _) = item {
+                offsets.push(reader.current_value_offset().unwrap());
+            }
+        }
+        Ok(LazyDocument {
+            text,
+            offsets,
+            values_parsed: Cell::new(0),
+        })
+    }
+
+    /// Returns the number of top-level values in the document.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns `true` if the document does not contain any values.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Returns the source text of the top-level value at `index`, including any leading
+    /// whitespace, comments, or annotations. If `index` is out of bounds, returns None.
+    pub fn text_of(&self, index: usize) -> Option<&'a str> {
+        let start = *self.offsets.get(index)?;
+        let end = self
+            .offsets
+            .get(index + 1)
+            .copied()
+            .unwrap_or(self.text.len());
+        Some(&self.text[start..end])
+    }
+
+    /// Parses the top-level value at `index` and returns it as an [OwnedElement]. Only the text
+    /// of the requested value is parsed. The value is not cached; each call parses it again.
+    /// If `index` is out of bounds, returns `Ok(None)`.
+    pub fn get(&self, index: usize) -> IonResult<Option<OwnedElement>> {
+        let text = match self.text_of(index) {
+            Some(text) => text,
+            None => return Ok(None),
+        };
+        self.values_parsed.set(self.values_parsed.get() + 1);
+        // The value's text may be followed by an IVM; that isn't a value, so it will be skipped.
+        let element = RawTextReader::new(text)
+            .read_remaining_elements()?
+            .into_iter()
+            .next();
+        Ok(element)
+    }
+}
+
+#[cfg(test)]
+mod lazy_document_tests {
+    use crate::result::IonResult;
+    use crate::text::load_lazy;
+    use crate::value::owned::{text_token, OwnedElement};
+    use crate::value::{Builder, Element, Sequence, Struct};
+
+    #[test]
+    fn test_get_parses_only_the_requested_value() -> IonResult<()> {
+        let document = load_lazy("0 1 2 3")?;
+        assert_eq!(document.len(), 4);
+        assert_eq!(document.values_parsed.get(), 0);
+        assert_eq!(document.get(2)?, Some(OwnedElement::new_i64(2)));
+        assert_eq!(document.values_parsed.get(), 1);
+        assert_eq!(document.get(4)?, None);
+        assert_eq!(document.values_parsed.get(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_containers_and_annotations() -> IonResult<()> {
+        let document =
+            load_lazy("$ion_1_0 foo::[1, 2] /* comment */ {a: (b c)}\n$ion_1_0\n'''x'''")?;
+        assert_eq!(document.len(), 3);
+        assert_eq!(
+            document.text_of(1),
+            Some(" /* comment */ {a: (b c)}\n$ion_1_0")
+        );
+        let list = document.get(0)?.unwrap();
+        assert_eq!(
+            list.annotations().collect::<Vec<_>>(),
+            vec![&text_token("foo")]
+        );
+        assert_eq!(list.as_sequence().unwrap().len(), 2);
+        let structure = document.get(1)?.unwrap();
+        assert_eq!(
+            structure
+                .as_struct()
+                .unwrap()
+                .get("a")
+                .unwrap()
+                .as_sequence()
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(document.get(2)?, Some(OwnedElement::new_string("x")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_lazy_errors() {
+        assert!(load_lazy("1 2 {a: ").is_err());
+        assert!(load_lazy("").unwrap().is_empty());
+    }
+}
@@ -1,3 +1,9 @@
+#[cfg(feature = "async")]
+mod async_text_reader;
+#[cfg(feature = "bench")]
+mod bench;
+mod delimiter_scanner;
+mod lazy_document;
 mod parent_container;
 pub(in crate::text) mod parsers;
 pub mod raw_text_reader;
@@ -5,3 +11,193 @@ mod text_buffer;
 mod text_data_source;
 mod text_value;
 pub mod writer;
+
+#[cfg(feature = "async")]
+pub use async_text_reader::AsyncTextReader;
+#[cfg(feature = "bench")]
+pub use bench::{parse_throughput, ParseMetrics};
+pub use lazy_document::LazyDocument;
+pub use text_data_source::LineIteratorSource;
+
+use crate::raw_reader::{RawReader, RawStreamItem};
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, IonResult};
+use crate::text::parsers::annotations::parse_annotation;
+use crate::text::parsers::comments::whitespace_or_comments;
+use crate::text::raw_text_reader::RawTextReader;
+use crate::value::owned::{local_sid_token, text_token, OwnedElement, OwnedSymbolToken};
+
+/// Reads the provided Ion text in its entirety, stepping into every container along the way.
+/// Returns `Ok(())` if the whole document could be parsed or the first error encountered if
+/// it could not. The reader fully parses each scalar value (allocating for strings, symbols, and
+/// lobs), but the values are discarded rather than returned.
+pub fn validate(input: &str) -> IonResult<()> {
+    let mut reader = RawTextReader::new(input);
+    loop {
+        match reader.next()? {
+            Some(RawStreamItem::Value(ion_type, false)) if ion_type.is_container() => {
+                reader.step_in()?
+            }
+            Some(_) => {}
+            None if reader.depth() > 0 => reader.step_out()?,
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Locates each of the top-level values in the provided Ion text without materializing them.
+/// Individual values can then be parsed on demand using [LazyDocument::get], which is useful when
+/// only a few of the values in a large document are needed. Returns an error if the text is
+/// malformed at the top level; the contents of containers are not validated until they are
+/// accessed.
+pub fn load_lazy(input: &str) -> IonResult<LazyDocument<'_>> {
+    LazyDocument::new(input)
+}
+
+/// Parses the provided Ion text, which must consist of a single top-level struct, and returns the
+/// value of the first field named `field` as an [OwnedElement]. Returns `Ok(None)` if the struct
+/// has no such field. Fields whose names were written as symbol IDs are never matched. Returns a
+/// decoding error if the text is not a single non-null struct.
+pub fn get_field(input: &str, field: &str) -> IonResult<Option<OwnedElement>> {
+    let mut reader = RawTextReader::new(input);
+    reader.read_single_struct()?;
+    while let Some((field_name, _ion_type)) = reader.next_field()? {
+        if field_name == Some(field) {
+            return Ok(Some(reader.read_element()?));
+        }
+    }
+    Ok(None)
+}
+
+/// Parses the sequence of annotations (e.g. `foo::'bar baz'::$10::`) at the beginning of `input`.
+/// Returns the annotations along with the remaining text, which begins at the annotated value.
+/// If `input` does not begin with an annotation, returns an empty Vec and the original input.
+/// Returns an error if the annotations are not followed by a value.
+pub fn parse_annotations(input: &str) -> IonResult<(Vec<OwnedSymbolToken>, &str)> {
+    let mut annotations = Vec::new();
+    let mut remaining = input;
+    while let Ok((remaining_input, annotation)) = parse_annotation(remaining) {
+        annotations.push(match annotation {
+            RawSymbolToken::Text(text) => text_token(text),
+            RawSymbolToken::SymbolId(sid) => local_sid_token(sid),
+        });
+        remaining = remaining_input;
+    }
+    if annotations.is_empty() {
+        return Ok((annotations, input));
+    }
+    // Skip any whitespace or comments between the last annotation and the value.
+    match whitespace_or_comments(remaining) {
+        Ok((value_text, _)) if !value_text.is_empty() => Ok((annotations, value_text)),
+        _ => decoding_error(format!(
+            "The annotations in '{}' were not followed by a value.",
+            input
+        )),
+    }
+}
+
+#[cfg(test)]
+mod parse_annotations_tests {
+    use super::parse_annotations;
+    use crate::value::owned::{local_sid_token, text_token};
+
+    #[test]
+    fn test_parse_annotations() {
+        let (annotations, remaining) = parse_annotations("a::b::5").unwrap();
+        assert_eq!(annotations, vec![text_token("a"), text_token("b")]);
+        assert_eq!(remaining, "5");
+
+        let (annotations, remaining) =
+            parse_annotations("'foo bar' :: $10:: /* comment */ [1, 2]").unwrap();
+        assert_eq!(
+            annotations,
+            vec![text_token("foo bar"), local_sid_token(10)]
+        );
+        assert_eq!(remaining, "[1, 2]");
+    }
+
+    #[test]
+    fn test_parse_annotations_none() {
+        let (annotations, remaining) = parse_annotations("5").unwrap();
+        assert!(annotations.is_empty());
+        assert_eq!(remaining, "5");
+
+        // A symbol that is not followed by '::' is a value, not an annotation.
+        let (annotations, remaining) = parse_annotations("foo").unwrap();
+        assert!(annotations.is_empty());
+        assert_eq!(remaining, "foo");
+    }
+
+    #[test]
+    fn test_parse_annotations_without_value() {
+        assert!(parse_annotations("a::").is_err());
+        assert!(parse_annotations("a::b::   ").is_err());
+    }
+}
+
+#[cfg(test)]
+mod get_field_tests {
+    use super::get_field;
+    use crate::value::owned::OwnedElement;
+    use crate::value::Builder;
+
+    #[test]
+    fn test_get_field() {
+        let ion_data = "{a:1, b:[2,3], a:4}";
+        assert_eq!(
+            get_field(ion_data, "b").unwrap(),
+            Some(OwnedElement::new_list(vec![
+                OwnedElement::from(2i64),
+                OwnedElement::from(3i64)
+            ]))
+        );
+        // The first match wins
+        assert_eq!(
+            get_field(ion_data, "a").unwrap(),
+            Some(OwnedElement::from(1i64))
+        );
+        assert_eq!(get_field(ion_data, "c").unwrap(), None);
+        assert_eq!(get_field("$ion_1_0 {$10: 1}", "$10").unwrap(), None);
+    }
+
+    #[test]
+    fn test_get_field_not_a_single_struct() {
+        assert!(get_field("[1, 2]", "a").is_err());
+        assert!(get_field("null.struct", "a").is_err());
+        assert!(get_field("{a: 1} {a: 2}", "a").is_err());
+        assert!(get_field("", "a").is_err());
+    }
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use super::validate;
+
+    #[test]
+    fn test_validate_valid_document() {
+        let ion_data = r#"
+            $ion_1_0
+            // A comment
+            foo::{
+                bar: [1, 2.5, 3e0, "four", five, (6 + 7)],
+                'baz': {{aGVsbG8=}},
+                quux: {{"clob"}},
+                empty: {},
+                nulls: [null, null.list, null.sexp, null.struct, null.string],
+                when: 2021-01-01T00:00:00Z,
+            }
+            /* Another comment */
+            '''long''' '''string'''
+            42
+        "#;
+        assert!(validate(ion_data).is_ok());
+        assert!(validate("").is_ok());
+    }
+
+    #[test]
+    fn test_validate_invalid_document() {
+        assert!(validate("{foo: [1, 2, 3}").is_err());
+        assert!(validate("[1, 2] {a: 1 b: 2}").is_err());
+        assert!(validate("(1 2 3) 1.2.3").is_err());
+    }
+}
@@ -60,6 +60,9 @@ mod parse_annotations_tests {
     #[case::quoted_trailing_spaces("'foo'::   ", "foo")]
     #[case::quoted_interstitial_spaces("'foo'   ::", "foo")]
     #[case::quoted_all_spaces("   'foo'   ::   ", "foo")]
+    #[case::quoted_escaped_tab(r"'a\tb'::", "a\tb")]
+    #[case::quoted_escaped_quote(r"'don\'t'::", "don't")]
+    #[case::quoted_escaped_unicode(r"'\u00e9t\u00e9'::", "été")]
     fn test_parse_annotation(#[case] text: &str, #[case] expected: &str) {
         assert_eq!(parse_annotation(text).unwrap().1, text_token(expected));
     }
@@ -73,4 +76,20 @@ mod parse_annotations_tests {
     fn test_parse_symbol_id_annotation(#[case] text: &str, #[case] expected: SymbolId) {
         assert_eq!(parse_annotation(text).unwrap().1, local_sid_token(expected));
     }
+
+    #[test]
+    fn test_parse_duplicate_annotations() {
+        // Repeated annotations are preserved in order rather than collapsed.
+        let (remaining, annotations) = parse_annotations("a::a::b::'a'::5 ").unwrap();
+        assert_eq!(remaining, "5 ");
+        assert_eq!(
+            annotations,
+            vec![
+                text_token("a"),
+                text_token("a"),
+                text_token("b"),
+                text_token("a")
+            ]
+        );
+    }
 }
@@ -6,16 +6,32 @@ use nom::bytes::streaming::{is_not, tag, take_until};
 use nom::character::streaming::char;
 use nom::combinator::{map, not, opt, peek, verify};
 use nom::multi::{fold_many0, many1};
-use nom::sequence::{delimited, terminated};
+use nom::sequence::{delimited, pair, terminated};
 use nom::IResult;
 use std::str;
 
 /// Matches the text representation of a clob value and returns the resulting [Clob]
-/// as a [TextValue::Clob].
+/// as a [TextValue::Clob]. Whitespace is permitted between the braces and the clob's body, but
+/// comments are not; the Ion spec does not allow comments anywhere inside a lob.
 pub(crate) fn parse_clob(input: &str) -> IResult<&str, TextValue> {
-    map(delimited(tag("{{"), parse_clob_body, tag("}}")), |text| {
-        text
-    })(input)
+    alt((
+        unescaped_short_clob,
+        delimited(
+            pair(tag("{{"), opt(whitespace)),
+            parse_clob_body,
+            pair(opt(whitespace), tag("}}")),
+        ),
+    ))(input)
+}
+
+/// Matches a short clob with no escape sequences or surrounding whitespace (e.g. `{{"hello"}}`)
+/// and returns a [TextValue::UnescapedClob] recording the length of its body. This is the most
+/// common form of clob and does not require its bytes to be copied out of the input.
+fn unescaped_short_clob(input: &str) -> IResult<&str, TextValue> {
+    map(
+        delimited(tag("{{\""), opt(is_not("\"\\")), tag("\"}}")),
+        |body: Option<&str>| TextValue::UnescapedClob(body.map(str::len).unwrap_or(0)),
+    )(input)
 }
 
 /// Matches the body of a clob value (e.g. "Hello" in {{"Hello"}})
@@ -124,12 +140,23 @@ mod clob_parsing_tests {
     use std::iter::FromIterator;
 
     use crate::text::parsers::clob::parse_clob;
-    use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
+    use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok, parse_unwrap};
     use crate::text::text_value::TextValue;
 
     fn parse_equals<A: AsRef<[u8]>>(text: &str, expected: A) {
         let data = Vec::from_iter(expected.as_ref().iter().copied());
-        parse_test_ok(parse_clob, text, TextValue::Clob(data))
+        // Unescaped clobs record where their bytes are in the input rather than copying them.
+        let actual = match parse_unwrap(parse_clob, text) {
+            TextValue::UnescapedClob(length) => {
+                TextValue::Clob(Vec::from(&text.as_bytes()[3..3 + length]))
+            }
+            other => other,
+        };
+        assert_eq!(actual, TextValue::Clob(data));
+    }
+
+    fn parse_unescaped(text: &str, expected_length: usize) {
+        parse_test_ok(parse_clob, text, TextValue::UnescapedClob(expected_length))
     }
 
     fn parse_fails(text: &str) {
@@ -147,5 +174,27 @@ mod clob_parsing_tests {
         parse_equals("{{'''Hello''' '''world'''}}", "Helloworld");
         parse_equals("{{'''Hello world'''}}", "Hello world");
         parse_equals("{{'''\\xe2\\x9d\\xa4\\xef\\xb8\\x8f\'''}}", "❤️");
+
+        // whitespace around the body and between long clob segments
+        parse_equals("{{ \"hello\" }}", "hello");
+        parse_equals("{{ '''ab''' '''cd''' }}", "abcd");
+        parse_equals("{{\n  '''ab'''\n  '''cd'''\n}}", "abcd");
+
+        // comments are not allowed inside a lob
+        parse_fails("{{ '''ab''' /*c*/ '''cd''' }}");
+        parse_fails("{{ /*c*/ \"hello\" }}");
+    }
+
+    #[test]
+    fn test_parse_unescaped_clobs() {
+        parse_unescaped("{{\"hello\"}}\n", 5);
+        parse_unescaped("{{\"\"}}\n", 0);
+        // Clobs with escapes must be decoded and are parsed as TextValue::Clob
+        parse_test_ok(
+            parse_clob,
+            "{{\"a\\nb\"}}\n",
+            TextValue::Clob(Vec::from("a\nb".as_bytes())),
+        );
+        parse_fails("{{\"hello}}\n");
     }
 }
@@ -141,7 +141,7 @@ pub(crate) fn s_expression_value_or_end(input: &str) -> IResult<&str, Option<Ann
 
 /// Matches a whitespace character (which is consumed) or an end-of-container (which is not consumed).
 pub(crate) fn s_expression_delimiter(input: &str) -> IResult<&str, ()> {
-    alt((recognize(one_of(" \t\r\n")), peek(list_end)))
+    alt((recognize(one_of(" \t\r\n")), peek(s_expression_end)))
         // TODO: This parser discards the matched &str as a workaround to a limitation in RawTextReader.
         //       See: https://github.com/amzn/ion-rust/issues/337
         .map(|_| ())
@@ -305,6 +305,22 @@ mod container_parsing_tests {
         parse_test_ok(s_expression_value_or_end, text, expected);
     }
 
+    #[rstest]
+    #[case(" ")]
+    #[case("\n")]
+    #[case(")")]
+    fn test_parse_s_expression_delimiter(#[case] text: &str) {
+        parse_test_ok(s_expression_delimiter, text, ());
+    }
+
+    #[rstest]
+    #[case("]")]
+    #[case("}")]
+    #[case(",")]
+    fn test_parse_s_expression_delimiter_err(#[case] text: &str) {
+        parse_test_err(s_expression_delimiter, text);
+    }
+
     #[rstest]
     #[case("5,", TextValue::Integer(5).without_annotations())]
     #[case("5  ,", TextValue::Integer(5).without_annotations())]
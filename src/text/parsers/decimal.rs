@@ -117,8 +117,11 @@ fn decimal_exponent_marker_followed_by_digits(input: &str) -> IResult<&str, &str
 
 #[cfg(test)]
 mod reader_tests {
+    use rstest::*;
+
     use crate::text::parsers::decimal::parse_decimal;
-    use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
+    use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok, parse_unwrap};
+    use crate::text::parsers::value::value;
     use crate::text::text_value::TextValue;
     use crate::types::decimal::Decimal;
 
@@ -140,6 +143,7 @@ mod reader_tests {
         parse_equals("-0d-5 ", Decimal::negative_zero_with_exponent(-5));
         parse_equals("305d1 ", Decimal::new(305, 1));
         parse_equals("305d-1 ", Decimal::new(305, -1));
+        parse_equals("305d+1 ", Decimal::new(305, 1));
         parse_equals("111_111d222 ", Decimal::new(111_111, 222));
         parse_equals("111_111d-222 ", Decimal::new(111_111, -222));
         parse_equals("111_111d222_222 ", Decimal::new(111_111, 222_222));
@@ -171,6 +175,25 @@ mod reader_tests {
         parse_fails("305d1");
     }
 
+    #[rstest]
+    #[case("1.2d3 ", Decimal::new(12, 2))]
+    #[case("1.2D3 ", Decimal::new(12, 2))]
+    #[case("1.2d-3 ", Decimal::new(12, -4))]
+    #[case("1.2D-3 ", Decimal::new(12, -4))]
+    #[case("1.2d+3 ", Decimal::new(12, 2))]
+    #[case("1.2D+3 ", Decimal::new(12, 2))]
+    fn test_parse_decimal_exponent_markers(#[case] text: &str, #[case] expected: Decimal) {
+        parse_equals(text, expected);
+    }
+
+    #[test]
+    fn test_float_exponent_marker_is_not_a_decimal() {
+        parse_fails("1.2e3 ");
+        parse_fails("1.2E3 ");
+        assert_eq!(parse_unwrap(value, "1.2e3 "), TextValue::Float(1200f64));
+        assert_eq!(parse_unwrap(value, "1.2E-3 "), TextValue::Float(0.0012f64));
+    }
+
     #[test]
     fn test_parse_decimals_without_exponents() {
         parse_equals("0. ", Decimal::new(0, 0));
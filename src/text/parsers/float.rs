@@ -91,6 +91,8 @@ mod float_parsing_tests {
         parse_equals("-0.279e3 ", -279.0);
         parse_equals("-279e0 ", -279.0);
         parse_equals("-279.5e0 ", -279.5);
+        parse_equals("5e+3 ", 5000.0);
+        parse_equals("5e-3 ", 0.005);
 
         // Missing exponent (would be parsed as an integer)
         parse_fails("305 ");
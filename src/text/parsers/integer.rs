@@ -8,7 +8,8 @@ use nom::combinator::{map_res, opt, recognize};
 use nom::multi::many0_count;
 use nom::sequence::{pair, preceded, separated_pair, terminated};
 use nom::IResult;
-use std::num::ParseIntError;
+use num_bigint::{BigInt, BigUint, Sign as BigIntSign};
+use std::num::{IntErrorKind, ParseIntError};
 
 // This module uses the phrase "base 10" to avoid potentially confusing references to "decimal",
 // a phrase which is heavily overloaded in the context of parsing Ion. It may refer to the Ion type
@@ -16,6 +17,10 @@ use std::num::ParseIntError;
 
 /// Matches the text representation of an integer in any supported notation (base-2, base-10, or
 /// base-16) and returns the resulting [i64] as a [TextValue::Integer].
+///
+/// Integers whose values do not fit in an i64 are clamped to [i64::MIN] or [i64::MAX]. Callers
+/// that need to reject them can use [is_out_of_range_integer] to distinguish them from integers
+/// that were written as exactly `i64::MIN` or `i64::MAX`.
 pub(crate) fn parse_integer(input: &str) -> IResult<&str, TextValue> {
     terminated(
         alt((base_16_integer, base_2_integer, base_10_integer)),
@@ -33,9 +38,12 @@ fn base_16_integer(input: &str) -> IResult<&str, TextValue> {
             base_16_integer_digits,
         ),
         |(maybe_sign, text_digits)| {
-            parse_i64_with_radix(text_digits, 16)
-                .map(|i| if maybe_sign.is_some() { -i } else { i })
-                .map(TextValue::Integer)
+            clamp_overflow(parse_signed_i64_with_radix(
+                maybe_sign.is_some(),
+                text_digits,
+                16,
+            ))
+            .map(TextValue::Integer)
         },
     )(input)
 }
@@ -66,9 +74,12 @@ fn base_2_integer(input: &str) -> IResult<&str, TextValue> {
             base_2_integer_digits,
         ),
         |(maybe_sign, text_digits)| {
-            parse_i64_with_radix(text_digits, 2)
-                .map(|i| if maybe_sign.is_some() { -i } else { i })
-                .map(TextValue::Integer)
+            clamp_overflow(parse_signed_i64_with_radix(
+                maybe_sign.is_some(),
+                text_digits,
+                2,
+            ))
+            .map(TextValue::Integer)
         },
     )(input)
 }
@@ -88,10 +99,73 @@ fn base_2_integer_digits(input: &str) -> IResult<&str, &str> {
 fn base_10_integer(input: &str) -> IResult<&str, TextValue> {
     map_res(
         recognize(preceded(opt(char('-')), base_10_integer_digits)),
-        |text| parse_i64_with_radix(text, 10).map(TextValue::Integer),
+        |text| clamp_overflow(parse_i64_with_radix(text, 10)).map(TextValue::Integer),
     )(input)
 }
 
+/// Parses the provided digits according to the specified radix, negating them if `is_negative` is
+/// true. The sign is applied before parsing so that `i64::MIN` (whose magnitude does not fit in an
+/// i64) can be represented.
+fn parse_signed_i64_with_radix(
+    is_negative: bool,
+    text_digits: &str,
+    radix: u32,
+) -> Result<i64, ParseIntError> {
+    if is_negative {
+        return parse_i64_with_radix(&format!("-{}", text_digits), radix);
+    }
+    parse_i64_with_radix(text_digits, radix)
+}
+
+/// Replaces an overflow error with the i64 bound that was exceeded. Other errors are returned as-is.
+fn clamp_overflow(result: Result<i64, ParseIntError>) -> Result<i64, ParseIntError> {
+    match result {
+        Err(e) if *e.kind() == IntErrorKind::PosOverflow => Ok(i64::MAX),
+        Err(e) if *e.kind() == IntErrorKind::NegOverflow => Ok(i64::MIN),
+        other => other,
+    }
+}
+
+/// Returns `true` if `text` is a well-formed integer in any supported notation whose value does
+/// not fit in an i64. The parsers above clamp such integers, and this check allows the reader to
+/// tell them apart from integers that were written as an i64 bound.
+pub(crate) fn is_out_of_range_integer(text: &str) -> bool {
+    let unsigned_text = text.strip_prefix('-').unwrap_or(text);
+    let (digits, radix) = split_radix_prefix(unsigned_text);
+    let is_well_formed = !digits.is_empty()
+        && !digits.starts_with('_')
+        && !digits.ends_with('_')
+        && !digits.contains("__")
+        && digits.chars().all(|c| c == '_' || c.is_digit(radix))
+        // Base-10 integers cannot have leading zeros
+        && !(radix == 10 && digits.len() > 1 && digits.starts_with('0'));
+    let is_negative = unsigned_text.len() < text.len();
+    is_well_formed && parse_signed_i64_with_radix(is_negative, digits, radix).is_err()
+}
+
+/// Returns the exact value of `text`, an integer in any supported notation that
+/// [is_out_of_range_integer] has accepted.
+pub(crate) fn parse_big_integer(text: &str) -> Option<BigInt> {
+    let unsigned_text = text.strip_prefix('-').unwrap_or(text);
+    let (digits, radix) = split_radix_prefix(unsigned_text);
+    let magnitude = BigUint::parse_bytes(digits.replace("_", "").as_bytes(), radix)?;
+    let sign = if unsigned_text.len() < text.len() {
+        BigIntSign::Minus
+    } else {
+        BigIntSign::Plus
+    };
+    Some(BigInt::from_biguint(sign, magnitude))
+}
+
+// Splits an unsigned integer's text into its digits and the radix indicated by its prefix.
+fn split_radix_prefix(unsigned_text: &str) -> (&str, u32) {
+    match unsigned_text.get(..2) {
+        Some("0x") | Some("0X") => (&unsigned_text[2..], 16),
+        Some("0b") | Some("0B") => (&unsigned_text[2..], 2),
+        _ => (unsigned_text, 10),
+    }
+}
+
 /// Strips any underscores out of the provided text and then parses it according to the specified
 /// radix.
 fn parse_i64_with_radix(text: &str, radix: u32) -> Result<i64, ParseIntError> {
@@ -104,7 +178,7 @@ fn parse_i64_with_radix(text: &str, radix: u32) -> Result<i64, ParseIntError> {
 
 #[cfg(test)]
 mod integer_parsing_tests {
-    use crate::text::parsers::integer::parse_integer;
+    use crate::text::parsers::integer::{is_out_of_range_integer, parse_integer};
     use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
     use crate::text::text_value::TextValue;
 
@@ -132,6 +206,36 @@ mod integer_parsing_tests {
         parse_fails("305");
     }
 
+    #[test]
+    fn test_parse_base_10_integer_boundaries() {
+        parse_equals("9223372036854775807 ", i64::MAX);
+        parse_equals("-9223372036854775808 ", i64::MIN);
+        // Out-of-range integers are clamped; the reader decides whether to reject them
+        parse_equals("9223372036854775808 ", i64::MAX);
+        parse_equals("-9223372036854775809 ", i64::MIN);
+        parse_equals("0x1_0000_0000_0000_0000 ", i64::MAX);
+        parse_equals("-0x8000_0000_0000_0001 ", i64::MIN);
+    }
+
+    #[test]
+    fn test_is_out_of_range_integer() {
+        assert!(is_out_of_range_integer("9223372036854775808"));
+        assert!(is_out_of_range_integer("-9223372036854775809"));
+        assert!(is_out_of_range_integer("9_223_372_036_854_775_808"));
+        assert!(is_out_of_range_integer("0x8000_0000_0000_0000"));
+        assert!(is_out_of_range_integer("-0x8000_0000_0000_0001"));
+        assert!(is_out_of_range_integer(&format!("0b1{}", "0".repeat(63))));
+
+        assert!(!is_out_of_range_integer("9223372036854775807"));
+        assert!(!is_out_of_range_integer("-9223372036854775808"));
+        assert!(!is_out_of_range_integer("-0x8000_0000_0000_0000"));
+        // Malformed integers are not out of range
+        assert!(!is_out_of_range_integer("0x"));
+        assert!(!is_out_of_range_integer("92233720368547758080.0"));
+        assert!(!is_out_of_range_integer("09223372036854775808"));
+        assert!(!is_out_of_range_integer("9223372036854775808_"));
+    }
+
     #[test]
     fn test_parse_base_10_integers_with_underscores() {
         parse_equals("111_111_222 ", 111_111_222);
@@ -155,6 +259,7 @@ mod integer_parsing_tests {
         parse_equals("-0b11110000 ", -240);
         parse_equals("0B11111111 ", 255);
         parse_equals("-0B11111111 ", -255);
+        parse_equals(&format!("-0b1{} ", "0".repeat(63)), i64::MIN);
 
         // Doesn't consume leading whitespace
         parse_fails(" 0b0011_0001 ");
@@ -162,6 +267,9 @@ mod integer_parsing_tests {
         parse_fails("+0b0011_0001 ");
         // Doesn't accept multiple negative signs
         parse_fails("--0b0011_0001 ");
+        // Doesn't accept a sign after the radix prefix
+        parse_fails("0b-101 ");
+        parse_fails("-0b-101 ");
         // Doesn't accept a number if it's the last thing in the input (might be incomplete stream)
         parse_fails("0b0011_0001");
     }
@@ -188,8 +296,11 @@ mod integer_parsing_tests {
         parse_equals("0xA ", 10);
         parse_equals("0xFF ", 255);
         parse_equals("0xff ", 255);
+        parse_equals("-0xFF ", -255);
         parse_equals("0XfF ", 255);
         parse_equals("-0xDECAF ", -912559);
+        parse_equals("0x7FFF_FFFF_FFFF_FFFF ", i64::MAX);
+        parse_equals("-0x8000_0000_0000_0000 ", i64::MIN);
 
         // Doesn't consume leading whitespace
         parse_fails(" 0xCAFE ");
@@ -197,6 +308,10 @@ mod integer_parsing_tests {
         parse_fails("+0xCAFE ");
         // Doesn't accept multiple negative signs
         parse_fails("--0xCAFE ");
+        // Doesn't accept a sign after the radix prefix
+        parse_fails("0x-1 ");
+        parse_fails("0x-FF ");
+        parse_fails("-0x-FF ");
         // Doesn't accept a number if it's the last thing in the input (might be incomplete stream)
         parse_fails("0xCAFE");
     }
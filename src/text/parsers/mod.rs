@@ -12,7 +12,7 @@ pub(crate) mod annotations;
 pub(crate) mod blob;
 pub(crate) mod boolean;
 pub(crate) mod clob;
-mod comments;
+pub(crate) mod comments;
 pub(crate) mod containers;
 pub(crate) mod decimal;
 pub(crate) mod float;
@@ -63,7 +63,7 @@ pub(crate) fn floating_point_number(input: &str) -> IResult<&str, &str> {
 /// Recognizes the exponent portion of a decimal (everything after the 'd') or float
 /// (everything after the 'e').
 pub(crate) fn exponent_digits(input: &str) -> IResult<&str, &str> {
-    recognize(pair(opt(char('-')), base_10_integer_digits))(input)
+    recognize(pair(opt(one_of("+-")), base_10_integer_digits))(input)
 }
 
 /// Recognizes a decimal point followed by some number of base-10 digits.
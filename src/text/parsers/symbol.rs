@@ -90,7 +90,7 @@ fn identifier_initial_character(input: &str) -> IResult<&str, char> {
 }
 
 /// Matches any character that is legal in an identifier, though not necessarily at the beginning.
-fn identifier_trailing_character(input: &str) -> IResult<&str, char> {
+pub(crate) fn identifier_trailing_character(input: &str) -> IResult<&str, char> {
     alt((one_of("$_"), satisfy(|c| c.is_ascii_alphanumeric())))(input)
 }
 
@@ -99,6 +99,9 @@ fn identifier_trailing_characters(input: &str) -> IResult<&str, &str> {
     recognize(many0_count(identifier_trailing_character))(input)
 }
 
+/// The characters that can appear in an operator symbol (e.g. `++` or `@`).
+pub(crate) const OPERATOR_CHARACTERS: &str = "!#%&*+-./;<=>?@^`|~";
+
 /// Matches an operator (e.g. `++` or `@`) and returns the resulting [String]
 /// as a [TextValue::Symbol]. This symbol syntax is only recognized inside of an s-expression.
 pub(crate) fn parse_operator(input: &str) -> IResult<&str, TextValue> {
@@ -114,7 +117,7 @@ pub(crate) fn parse_operator(input: &str) -> IResult<&str, TextValue> {
         // the whitespace on its own.
         multispace0,
         // `is_a` matches the longest leading string comprised of one or more of the given characters
-        is_a("!#%&*+-./;<=>?@^`|~"),
+        is_a(OPERATOR_CHARACTERS),
         // The operator must be followed either by whitespace or the end of the s-expression.
         alt((
             peek(recognize(one_of(" \r\n\t"))),
@@ -200,6 +203,15 @@ mod symbol_parsing_tests {
         parse_equals("'foo bar baz' ", "foo bar baz");
         parse_equals("'foo \"bar\" baz' ", "foo \"bar\" baz");
         parse_equals("'7@#$%^&*()!' ", "7@#$%^&*()!");
+        // Operator characters have no special meaning inside quotes
+        parse_equals("'+-*/' ", "+-*/");
+        parse_equals("'+-*/')", "+-*/");
+        // Escapes
+        parse_equals("'don\\'t' ", "don't");
+        parse_equals("'\\x2b\\u002d*/' ", "+-*/");
+        parse_equals("'a\\\\b' ", "a\\b");
+        // The empty symbol
+        parse_equals("'' ", "");
 
         // Leading whitespace not accepted
         parse_fails(" 'foo' ");
@@ -5,7 +5,7 @@ use nom::branch::alt;
 use nom::bytes::streaming::tag;
 use nom::character::complete::digit1;
 use nom::character::streaming::{char, one_of};
-use nom::combinator::{map, map_res, opt, recognize};
+use nom::combinator::{complete, map, map_res, opt, recognize};
 use nom::sequence::{pair, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
 use num_bigint::BigUint;
@@ -145,6 +145,35 @@ fn timestamp_precision_ymd_hms_fractional(input: &str) -> IResult<&str, TextValu
     )(input)
 }
 
+/// Returns `true` if `text` begins with a timestamp that has a time of day (e.g. `2021-01-01T00:00`)
+/// but no timezone offset. Ion requires that such timestamps end in an offset; the parsers above
+/// reject them, and this check allows the reader to explain why.
+pub(crate) fn is_missing_timezone_offset(text: &str) -> bool {
+    let mut time_of_day = recognize(tuple((
+        year,
+        month,
+        day,
+        hour_and_minute,
+        opt(complete(second)),
+        opt(complete(recognize_fractional_seconds)),
+    )));
+    match time_of_day(text) {
+        // The time of day must be the end of the token; anything else (an offset, or an invalid
+        // number of seconds) means the offset isn't what's missing.
+        Ok((remaining_text, _)) => {
+            remaining_text.is_empty() || stop_character(remaining_text).is_ok()
+        }
+        Err(_) => false,
+    }
+}
+
+/// Returns `true` if `text` begins with a four-digit year followed by `-` or `T`. No other kind of
+/// value can begin this way, so a token that does but that the parsers above reject is a malformed
+/// timestamp rather than a malformed number.
+pub(crate) fn looks_like_timestamp(text: &str) -> bool {
+    pair(year, one_of::<_, _, nom::error::Error<&str>>("-T"))(text).is_ok()
+}
+
 /// Parses the fractional seconds and stores it in the [FractionalSecondSetter].
 fn assign_fractional_seconds(
     fractional: &str,
@@ -282,11 +311,14 @@ fn timezone_offset_hours_minutes(input: &str) -> IResult<&str, (&str, &str)> {
 #[cfg(test)]
 mod reader_tests {
     use crate::result::IonResult;
-    use crate::text::parsers::timestamp::parse_timestamp;
+    use crate::text::parsers::timestamp::{
+        is_missing_timezone_offset, looks_like_timestamp, parse_timestamp,
+    };
     use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
     use crate::text::text_value::TextValue;
     use crate::types::decimal::Decimal;
-    use crate::types::timestamp::Timestamp;
+    use crate::types::timestamp::{Precision, Timestamp};
+    use rstest::*;
 
     fn parse_equals(text: &str, expected: Timestamp) {
         parse_test_ok(parse_timestamp, text, TextValue::Timestamp(expected))
@@ -352,6 +384,43 @@ mod reader_tests {
         Ok(())
     }
 
+    fn parse_timestamp_text(text: &str) -> Timestamp {
+        match parse_timestamp(text) {
+            Ok((_remaining, TextValue::Timestamp(timestamp))) => timestamp,
+            other => panic!("expected a timestamp, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_coarse_precisions() {
+        let timestamp = parse_timestamp_text("2021T ");
+        assert_eq!(timestamp.precision(), Precision::Year);
+        assert_eq!(timestamp.year(), 2021);
+        assert_eq!(timestamp.month(), None);
+        assert_eq!(timestamp.day(), None);
+        assert_eq!(timestamp.offset(), None);
+
+        let timestamp = parse_timestamp_text("2021-06T ");
+        assert_eq!(timestamp.precision(), Precision::Month);
+        assert_eq!(timestamp.year(), 2021);
+        assert_eq!(timestamp.month(), Some(6));
+        assert_eq!(timestamp.day(), None);
+        assert_eq!(timestamp.offset(), None);
+
+        let timestamp = parse_timestamp_text("2021-06-15T ");
+        assert_eq!(timestamp.precision(), Precision::Day);
+        assert_eq!(timestamp.year(), 2021);
+        assert_eq!(timestamp.month(), Some(6));
+        assert_eq!(timestamp.day(), Some(15));
+        assert_eq!(timestamp.offset(), None);
+
+        // The trailing 'T' is optional at day precision
+        let timestamp = parse_timestamp_text("2021-06-15 ");
+        assert_eq!(timestamp.precision(), Precision::Day);
+        assert_eq!(timestamp.day(), Some(15));
+        assert_eq!(timestamp.offset(), None);
+    }
+
     #[test]
     fn test_parse_timestamp_ymd_hm() -> IonResult<()> {
         let builder = Timestamp::with_ymd(2021, 9, 30);
@@ -385,9 +454,26 @@ mod reader_tests {
 
         // Missing offset
         parse_fails("2021-09-01T23:11");
+        parse_fails("2021-01-01T00:00 ");
+        assert!(is_missing_timezone_offset("2021-01-01T00:00 "));
+        assert!(is_missing_timezone_offset("2021-01-01T00:00"));
+        assert!(!is_missing_timezone_offset("2021-01-01T00:00Z "));
+        assert!(!is_missing_timezone_offset("2021-01-01T00:00:60Z "));
         Ok(())
     }
 
+    #[rstest]
+    #[case("2021T", true)]
+    #[case("2021-13-01T", true)]
+    #[case("0001-01-01T00:00Z", true)]
+    #[case("2021", false)]
+    #[case("2021.5", false)]
+    #[case("202-01-01T", false)]
+    #[case("1234_5678", false)]
+    fn test_looks_like_timestamp(#[case] text: &str, #[case] expected: bool) {
+        assert_eq!(looks_like_timestamp(text), expected);
+    }
+
     #[test]
     fn test_parse_timestamp_ymd_hms() -> IonResult<()> {
         let builder = Timestamp::with_ymd(2021, 12, 25);
@@ -1,8 +1,12 @@
+use nom::branch::alt;
 use nom::bytes::streaming::tag;
-use nom::sequence::preceded;
+use nom::character::complete::{char as complete_char, digit1 as complete_digit1};
+use nom::combinator::{complete, map_res, not, value, verify};
+use nom::sequence::{pair, preceded, separated_pair, terminated};
 use nom::{IResult, Parser};
 
 use crate::text::parsers::comments::whitespace_or_comments;
+use crate::text::parsers::symbol::identifier_trailing_character;
 use crate::text::parsers::value::annotated_value;
 use crate::text::text_value::AnnotatedTextValue;
 
@@ -18,14 +22,43 @@ pub(crate) fn top_level_value(input: &str) -> IResult<&str, AnnotatedTextValue>
     )(input)
 }
 
-// Matches any amount of whitespace/comments followed by the identifier `$ion_1_0`.
+// Matches any amount of whitespace/comments followed by an identifier of the form `$ion_X_Y`,
+// returning the (major, minor) version it specifies.
 // Note that this MUST be an identifier (i.e. an unquoted symbol) and not any other encoding of the
 // same symbol value. For more information see:
 // https://amzn.github.io/ion-docs/docs/symbols.html#ion-version-markers
+//
+// The identifier must match the pattern exactly; other identifiers that begin with the same text
+// (like `$ion_1_0_foo`) are ordinary symbols. An annotation (`$ion_1_0::foo`) is not an IVM either.
+pub(crate) fn ion_version_marker(input: &str) -> IResult<&str, (u32, u32)> {
+    // The text buffer always holds complete lines, so reaching the end of the input after `$ion_`
+    // means that the identifier has ended and it is not followed by `::` on the same line.
+    // Version numbers cannot have leading zeros; `$ion_1_00` is an ordinary symbol.
+    let version_number = || {
+        map_res(
+            verify(complete_digit1, |text: &str| {
+                text == "0" || !text.starts_with('0')
+            }),
+            |text: &str| text.parse::<u32>(),
+        )
+    };
+    terminated(
+        preceded(
+            pair(whitespace_or_comments, tag("$ion_")),
+            separated_pair(version_number(), complete_char('_'), version_number()),
+        ),
+        not(complete(alt((
+            value((), identifier_trailing_character),
+            value((), preceded(whitespace_or_comments, tag("::"))),
+        )))),
+    )(input)
+}
+
+// Matches any amount of whitespace/comments followed by the identifier `$ion_1_0`.
 pub(crate) fn ion_1_0_version_marker(input: &str) -> IResult<&str, ()> {
-    preceded(whitespace_or_comments, tag("$ion_1_0"))
-        // TODO: This parser discards the matched &str as a workaround to a limitation in RawTextReader.
-        //       See: https://github.com/amzn/ion-rust/issues/337
+    verify(ion_version_marker, |version| *version == (1, 0))
+        // TODO: This parser discards the matched version as a workaround to a limitation in
+        //       RawTextReader. See: https://github.com/amzn/ion-rust/issues/337
         .map(|_| ())
         .parse(input)
 }
@@ -35,9 +68,10 @@ mod parse_top_level_values_tests {
     use rstest::*;
 
     use crate::raw_symbol_token::{text_token, RawSymbolToken};
-    use crate::text::parsers::unit_test_support::{parse_test_ok, parse_unwrap};
+    use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok, parse_unwrap};
     use crate::text::parsers::value::value;
     use crate::text::text_value::TextValue;
+    use crate::types::decimal::Decimal;
     use crate::IonType;
 
     use super::*;
@@ -80,6 +114,19 @@ mod parse_top_level_values_tests {
         expect_type("{{\"hello\"}}", IonType::Clob);
     }
 
+    #[test]
+    fn test_decimal_float_disambiguation() {
+        // A number is only a float if it has an `e` or `E` exponent...
+        parse_test_ok(value, "1.5 ", TextValue::Decimal(Decimal::new(15, -1)));
+        parse_test_ok(value, "1.5e0 ", TextValue::Float(1.5));
+        parse_test_ok(value, "1.5E0 ", TextValue::Float(1.5));
+        parse_test_ok(value, "1.5d0 ", TextValue::Decimal(Decimal::new(15, -1)));
+        // ...and an exponent marker must be followed by digits. `1.5e` is not the decimal `1.5`
+        // followed by the symbol `e`.
+        parse_test_err(value, "1.5e ");
+        parse_test_err(value, "1.5e+ ");
+    }
+
     #[rstest]
     // For these tests, the input text must end in an unrelated value so the parser knows that
     // the first value is complete. For example, it's not possible to know whether this Ion data:
@@ -107,6 +154,32 @@ mod parse_top_level_values_tests {
         );
     }
 
+    #[test]
+    fn test_nan_disambiguation() {
+        // An unquoted `nan` is the float keyword...
+        let value = parse_unwrap(annotated_value, "nan ");
+        assert!(value.annotations().is_empty());
+        match value.value() {
+            TextValue::Float(f) => assert!(f.is_nan()),
+            other => panic!("Expected a NaN float, but got: {:?}", other),
+        }
+        // ...while a quoted 'nan' is a symbol...
+        parse_test_ok(
+            annotated_value,
+            "'nan' END",
+            TextValue::Symbol(text_token("nan")).without_annotations(),
+        );
+        // ...and can be used as an annotation.
+        parse_test_ok(
+            annotated_value,
+            "'nan'::5 END",
+            TextValue::Integer(5).with_annotations("nan"),
+        );
+        // Like `true`, `false`, and `null`, `nan` is a keyword; it must be quoted to be used as
+        // an annotation.
+        parse_test_err(annotated_value, "nan::5 END");
+    }
+
     #[rstest]
     #[case("$ion_1_0 ")]
     #[case("   \r  \t \n $ion_1_0 ")]
@@ -119,7 +192,24 @@ mod parse_top_level_values_tests {
     #[case("$2")]
     #[should_panic]
     #[case("$ion_1_1")]
+    #[should_panic]
+    #[case("$ion_1_0_foo ")]
+    #[should_panic]
+    #[case("$ion_1_00 ")]
+    #[should_panic]
+    #[case("$ion_1_0::foo ")]
+    #[should_panic]
+    #[case("$ion_1_0 /*comment*/ :: foo ")]
     fn test_parse_ion_version_marker(#[case] text: &str) {
         parse_test_ok(ion_1_0_version_marker, text, ());
     }
+
+    #[rstest]
+    #[case("$ion_1_0 ", (1, 0))]
+    #[case("$ion_1_1 ", (1, 1))]
+    #[case("$ion_2_0\n", (2, 0))]
+    #[case("$ion_10_20", (10, 20))]
+    fn test_parse_ion_version_marker_versions(#[case] text: &str, #[case] expected: (u32, u32)) {
+        parse_test_ok(ion_version_marker, text, expected);
+    }
 }
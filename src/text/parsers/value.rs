@@ -1,11 +1,12 @@
 use nom::branch::alt;
-use nom::sequence::pair;
+use nom::sequence::{pair, preceded};
 use nom::{IResult, Parser};
 
 use crate::text::parsers::annotations::parse_annotations;
 use crate::text::parsers::blob::parse_blob;
 use crate::text::parsers::boolean::parse_boolean;
 use crate::text::parsers::clob::parse_clob;
+use crate::text::parsers::comments::whitespace_or_comments;
 use crate::text::parsers::containers::container_start;
 use crate::text::parsers::decimal::parse_decimal;
 use crate::text::parsers::float::parse_float;
@@ -23,6 +24,9 @@ pub(crate) fn value(input: &str) -> IResult<&str, TextValue> {
 
 /// Matches a scalar (non-container) Ion value at the beginning of the given string and
 /// returns it as a [TextValue].
+///
+/// Integers whose values do not fit in an i64 are clamped to [i64::MIN] or [i64::MAX] rather than
+/// rejected; see [parse_integer].
 pub(crate) fn scalar(input: &str) -> IResult<&str, TextValue> {
     alt((
         parse_null,
@@ -45,15 +49,21 @@ pub(crate) fn scalar(input: &str) -> IResult<&str, TextValue> {
 /// Matches an optional series of annotations and their associated TextValue.
 pub(crate) fn annotated_value(input: &str) -> IResult<&str, AnnotatedTextValue> {
     alt((
-        pair(parse_annotations, value).map(|(a, v)| v.with_annotations(a)),
+        // Whitespace and comments may appear between the last `::` and the value.
+        pair(parse_annotations, preceded(whitespace_or_comments, value))
+            .map(|(a, v)| v.with_annotations(a)),
         value.map(|v| v.without_annotations()),
     ))(input)
 }
 
 /// Matches an optional series of annotations and their associated scalar TextValue.
+///
+/// Like [scalar], this clamps integers whose values do not fit in an i64 to [i64::MIN] or
+/// [i64::MAX].
 pub(crate) fn annotated_scalar(input: &str) -> IResult<&str, AnnotatedTextValue> {
     alt((
-        pair(parse_annotations, scalar).map(|(a, v)| v.with_annotations(a)),
+        pair(parse_annotations, preceded(whitespace_or_comments, scalar))
+            .map(|(a, v)| v.with_annotations(a)),
         scalar.map(|v| v.without_annotations()),
     ))(input)
 }
@@ -61,7 +71,11 @@ pub(crate) fn annotated_scalar(input: &str) -> IResult<&str, AnnotatedTextValue>
 /// Matches an optional series of annotations and their associated scalar TextValue.
 pub(crate) fn annotated_container_start(input: &str) -> IResult<&str, AnnotatedTextValue> {
     alt((
-        pair(parse_annotations, container_start).map(|(a, v)| v.with_annotations(a)),
+        pair(
+            parse_annotations,
+            preceded(whitespace_or_comments, container_start),
+        )
+        .map(|(a, v)| v.with_annotations(a)),
         container_start.map(|v| v.without_annotations()),
     ))(input)
 }
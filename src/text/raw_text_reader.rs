@@ -1,25 +1,91 @@
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use nom::bytes::streaming::tag;
+use nom::combinator::opt;
+use nom::sequence::preceded;
 use nom::Err::Incomplete;
 use nom::IResult;
+use num_bigint::{BigInt, BigUint, Sign as BigIntSign};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Formatter};
+use std::io::Write;
+use std::ops::Range;
+use std::sync::Arc;
 
 use crate::raw_reader::RawStreamItem;
 use crate::raw_symbol_token::RawSymbolToken;
-use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::result::{decoding_error, decoding_error_raw, illegal_operation, IonResult};
+use crate::text::delimiter_scanner::DelimiterScanner;
 use crate::text::parent_container::ParentContainer;
+use crate::text::parsers::annotations::{parse_annotation, parse_annotations};
+use crate::text::parsers::comments::whitespace_or_comments;
 use crate::text::parsers::containers::{
     list_delimiter, list_value_or_end, s_expression_delimiter, s_expression_value_or_end,
     struct_delimiter, struct_field_name_or_end, struct_field_value,
 };
-use crate::text::parsers::top_level::{ion_1_0_version_marker, top_level_value};
+use crate::text::parsers::integer::{is_out_of_range_integer, parse_big_integer};
+use crate::text::parsers::symbol::{parse_symbol, OPERATOR_CHARACTERS};
+use crate::text::parsers::timestamp::{is_missing_timezone_offset, looks_like_timestamp};
+use crate::text::parsers::top_level::{ion_version_marker, top_level_value};
+use crate::text::parsers::value::scalar;
 use crate::text::text_buffer::TextBuffer;
-use crate::text::text_data_source::TextIonDataSource;
+use crate::text::text_data_source::{LineIteratorSource, TextIonDataSource};
 use crate::text::text_value::{AnnotatedTextValue, TextValue};
+use crate::text::writer::{decimal_text, quote_symbol_if_needed, TextWriter};
+use crate::types::coefficient::Sign as CoefficientSign;
 use crate::types::decimal::Decimal;
-use crate::types::timestamp::Timestamp;
-use crate::{IonType, RawReader};
+use crate::types::timestamp::{Precision, Timestamp};
+use crate::types::SymbolId;
+use crate::value::owned::{self, OwnedElement, OwnedSequence, OwnedSymbolToken, OwnedValue};
+use crate::value::AnyInt;
+use crate::{IonDeserialize, IonType, RawReader};
 
 const INITIAL_PARENTS_CAPACITY: usize = 16;
+const MAX_INTERNED_SYMBOLS: usize = 1024;
+
+/// The direction of a sign written explicitly in the text of a numeric value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Polarity {
+    Positive,
+    Negative,
+}
+
+/// Indicates whether a numeric value's text began with an explicit `+` or `-`.
+///
+/// Ion text does not permit a leading `+` on integers, decimals, or floats; only the special
+/// float value `+inf` can have an explicitly positive sign.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sign {
+    Explicit(Polarity),
+    Implicit,
+}
+
+/// Counts of the work a [RawTextReader] has done so far. See [RawTextReader::stats].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ReaderStats {
+    /// The number of scalar values (including nulls of scalar types) the reader has advanced to.
+    /// Values inside containers that were skipped without being stepped into are not counted.
+    pub scalars_read: usize,
+    /// The number of times the reader has stepped into a container.
+    pub containers_entered: usize,
+    /// The number of bytes of input that have been consumed.
+    pub bytes_consumed: usize,
+    /// The number of complete lines of input that have been consumed.
+    pub lines_consumed: usize,
+}
+
+/// A summary of the value over which a [RawTextReader] is positioned. See
+/// [RawTextReader::current_info].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueInfo {
+    pub ion_type: IonType,
+    pub is_null: bool,
+    /// The number of containers the reader has stepped into.
+    pub depth: usize,
+    pub annotation_count: usize,
+    /// The value's field name, if it is inside a struct.
+    pub field_name: Option<RawSymbolToken>,
+}
 
 pub struct RawTextReader<T: TextIonDataSource> {
     buffer: TextBuffer<T::TextSource>,
@@ -32,370 +98,1160 @@ pub struct RawTextReader<T: TextIonDataSource> {
     //     Some(major_version, minor_version)
     // Otherwise, it is None.
     current_ivm: Option<(u8, u8)>,
+    // The (major, minor) version specified by the most recently encountered IVM.
+    ion_version: (u8, u8),
+    // The range of the text buffer containing the current value. This includes any leading
+    // whitespace, comments, and annotations as well as any trailing delimiter. It is only valid
+    // while `current_value` is Some.
+    current_value_range: Range<usize>,
+    // The range of the text buffer containing the text matched by the most recent parse.
+    last_parsed_range: Range<usize>,
     bytes_read: usize,
     is_eof: bool,
     parents: Vec<ParentContainer>,
+    forbid_top_level_symbols: bool,
+    validate_skipped_containers: bool,
+    max_total_bytes: Option<usize>,
+    allow_trailing_commas: bool,
+    symbols_as_strings: bool,
+    integer_overflow_mode: IntegerOverflowMode,
+    // Whether the text matched by the most recent parse ended with a comma. A list value or struct
+    // field that is followed by a comma will have consumed it as its delimiter.
+    last_parse_ended_with_comma: bool,
+    // Symbol text that has been returned by `read_interned_symbol`. Repeated symbols share a
+    // single allocation. Cleared at each IVM and whenever it reaches `max_interned_symbols`.
+    interned_symbols: HashSet<Arc<str>>,
+    max_interned_symbols: usize,
+    // If set, every byte of text that the reader consumes is also written to this sink.
+    tee: Option<Box<dyn Write + Send>>,
+    // A value that `next_if` parsed but declined to advance to, along with its field name and
+    // the range of the buffer containing it. The next call to `next()` will return it.
+    deferred_value: Option<(AnnotatedTextValue, Option<RawSymbolToken>, Range<usize>)>,
+    // Whether the most recent attempt to parse the next value failed. `resync_to_next_top_level`
+    // can only be used after such a failure.
+    last_parse_failed: bool,
+    // Counts of the scalars read and containers entered so far. The remaining fields are
+    // populated from the reader's state by `stats()`.
+    stats: ReaderStats,
 }
 
-impl<T: TextIonDataSource> RawTextReader<T> {
-    pub fn new(input: T) -> RawTextReader<T> {
+/// Selects how a [RawTextReader] handles integers whose values do not fit in an `i64`.
+/// See [RawTextReaderBuilder::with_integer_overflow_mode].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IntegerOverflowMode {
+    /// `next()` returns a decoding error when it encounters an out-of-range integer. This is the
+    /// default.
+    Error,
+    /// `next()` accepts out-of-range integers. [RawTextReader::read_i64_clamped] will return
+    /// [i64::MIN] or [i64::MAX] for them, while `read_i64()` will return a decoding error.
+    /// Clamping only happens in `read_i64_clamped`; methods that can represent the exact value
+    /// (like [RawTextReader::read_int_parts] and [RawTextReader::read_as_string]) return it.
+    Clamp,
+}
+
+// Not derived because of the MSRV; see clippy.toml.
+impl Default for IntegerOverflowMode {
+    fn default() -> Self {
+        IntegerOverflowMode::Error
+    }
+}
+
+/// Configures and constructs a [RawTextReader].
+///
+/// ```
+/// use ion_rs::text::raw_text_reader::RawTextReaderBuilder;
+/// let reader = RawTextReaderBuilder::new()
+///     .with_initial_parents_capacity(64)
+///     .build("foo bar baz");
+/// ```
+pub struct RawTextReaderBuilder {
+    initial_parents_capacity: usize,
+    forbid_top_level_symbols: bool,
+    validate_skipped_containers: bool,
+    max_total_bytes: Option<usize>,
+    allow_trailing_commas: bool,
+    symbols_as_strings: bool,
+    integer_overflow_mode: IntegerOverflowMode,
+    max_interned_symbols: usize,
+    tee: Option<Box<dyn Write + Send>>,
+}
+
+impl RawTextReaderBuilder {
+    pub fn new() -> RawTextReaderBuilder {
+        RawTextReaderBuilder {
+            initial_parents_capacity: INITIAL_PARENTS_CAPACITY,
+            forbid_top_level_symbols: false,
+            validate_skipped_containers: true,
+            max_total_bytes: None,
+            allow_trailing_commas: true,
+            symbols_as_strings: false,
+            integer_overflow_mode: IntegerOverflowMode::Error,
+            max_interned_symbols: MAX_INTERNED_SYMBOLS,
+            tee: None,
+        }
+    }
+
+    /// Sets the number of container levels the reader can step into before the stack it uses to
+    /// track its parent containers needs to be reallocated. Defaults to 16.
+    pub fn with_initial_parents_capacity(mut self, capacity: usize) -> RawTextReaderBuilder {
+        self.initial_parents_capacity = capacity;
+        self
+    }
+
+    /// If set to `true`, the reader will return a decoding error when it encounters a symbol value
+    /// at the top level of the stream. In many text formats, a bare top-level symbol is more
+    /// likely to be a string that is missing its quotes than an intentional value. Symbols inside
+    /// containers and symbols used as annotations or field names are not affected.
+    /// Defaults to `false`.
+    pub fn with_forbid_top_level_symbols(mut self, forbid: bool) -> RawTextReaderBuilder {
+        self.forbid_top_level_symbols = forbid;
+        self
+    }
+
+    /// When the reader is positioned over a container and `next()` is called, the reader skips
+    /// over the container's contents. By default, it parses each of the skipped values, returning
+    /// an error if any of them is malformed. If set to `false`, the reader will instead scan ahead
+    /// for the matching closing delimiter without parsing the values inside, which is faster but
+    /// will not detect malformed values in containers that are never stepped into.
+    /// Defaults to `true`.
+    pub fn with_validate_skipped_containers(mut self, validate: bool) -> RawTextReaderBuilder {
+        self.validate_skipped_containers = validate;
+        self
+    }
+
+    /// Sets the maximum number of bytes the reader will load from its input over its lifetime.
+    /// If loading more text would cause the reader to exceed this limit, it will return a
+    /// decoding error instead. This prevents a stream that never ends (or a single line that
+    /// never ends) from causing the reader to consume unbounded memory.
+    /// By default, there is no limit.
+    pub fn with_max_total_bytes(mut self, max_total_bytes: usize) -> RawTextReaderBuilder {
+        self.max_total_bytes = Some(max_total_bytes);
+        self
+    }
+
+    /// The Ion text grammar permits a comma after the last value in a list or struct
+    /// (e.g. `[1, 2,]`), but many other text formats (including JSON) do not. If set to `false`,
+    /// the reader will return a decoding error when it encounters such a trailing comma.
+    /// Containers that are skipped without being validated are not checked.
+    /// Defaults to `true`.
+    pub fn with_allow_trailing_commas(mut self, allow: bool) -> RawTextReaderBuilder {
+        self.allow_trailing_commas = allow;
+        self
+    }
+
+    /// If set to `true`, the reader will report symbol values as strings: `next()` will return
+    /// [IonType::String] for them and `read_string()` will return their text. This is useful for
+    /// applications whose type systems only have strings. Annotations and field names are not
+    /// affected, and symbol IDs (like `$10`) remain symbols because their text is not known to
+    /// the raw reader. Defaults to `false`.
+    pub fn with_symbols_as_strings(mut self, symbols_as_strings: bool) -> RawTextReaderBuilder {
+        self.symbols_as_strings = symbols_as_strings;
+        self
+    }
+
+    /// Sets how the reader handles integers whose values do not fit in an `i64`. See
+    /// [IntegerOverflowMode] for the behavior of each mode. Defaults to
+    /// [IntegerOverflowMode::Error].
+    pub fn with_integer_overflow_mode(mut self, mode: IntegerOverflowMode) -> RawTextReaderBuilder {
+        self.integer_overflow_mode = mode;
+        self
+    }
+
+    /// Sets the number of distinct symbol texts that [RawTextReader::read_interned_symbol] will
+    /// cache before discarding its cache and starting over. Each cached text stays allocated until
+    /// the cache is discarded, so this bounds the memory that interning can retain in a stream
+    /// with many unique symbols. Defaults to 1024.
+    pub fn with_max_interned_symbols(
+        mut self,
+        max_interned_symbols: usize,
+    ) -> RawTextReaderBuilder {
+        self.max_interned_symbols = max_interned_symbols;
+        self
+    }
+
+    /// Configures the reader to write a copy of every byte of text it consumes to `sink`. This
+    /// includes any whitespace and comments between values, so once the reader has reached the
+    /// end of the stream, the sink will contain an exact copy of the input. Errors encountered
+    /// while writing to the sink are returned by the reader method that consumed the text.
+    /// By default, there is no sink.
+    pub fn with_tee<W: Write + Send + 'static>(mut self, sink: W) -> RawTextReaderBuilder {
+        self.tee = Some(Box::new(sink));
+        self
+    }
+
+    /// Constructs a [RawTextReader] that will read from the provided input using this
+    /// builder's configuration.
+    pub fn build<T: TextIonDataSource>(self, input: T) -> RawTextReader<T> {
         let text_source = input.to_text_ion_data_source();
         RawTextReader {
             buffer: TextBuffer::new(text_source),
             current_field_name: None,
             current_value: None,
             current_ivm: None,
+            ion_version: (1, 0),
+            current_value_range: 0..0,
+            last_parsed_range: 0..0,
             bytes_read: 0,
             is_eof: false,
-            parents: Vec::with_capacity(INITIAL_PARENTS_CAPACITY),
+            parents: Vec::with_capacity(self.initial_parents_capacity),
+            forbid_top_level_symbols: self.forbid_top_level_symbols,
+            validate_skipped_containers: self.validate_skipped_containers,
+            max_total_bytes: self.max_total_bytes,
+            allow_trailing_commas: self.allow_trailing_commas,
+            symbols_as_strings: self.symbols_as_strings,
+            integer_overflow_mode: self.integer_overflow_mode,
+            last_parse_ended_with_comma: false,
+            interned_symbols: HashSet::new(),
+            max_interned_symbols: self.max_interned_symbols,
+            tee: self.tee,
+            deferred_value: None,
+            last_parse_failed: false,
+            stats: ReaderStats::default(),
         }
     }
+}
+
+// The tee can't be printed, so only whether one has been configured is shown.
+impl Debug for RawTextReaderBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawTextReaderBuilder")
+            .field("initial_parents_capacity", &self.initial_parents_capacity)
+            .field("forbid_top_level_symbols", &self.forbid_top_level_symbols)
+            .field(
+                "validate_skipped_containers",
+                &self.validate_skipped_containers,
+            )
+            .field("max_total_bytes", &self.max_total_bytes)
+            .field("allow_trailing_commas", &self.allow_trailing_commas)
+            .field("symbols_as_strings", &self.symbols_as_strings)
+            .field("integer_overflow_mode", &self.integer_overflow_mode)
+            .field("max_interned_symbols", &self.max_interned_symbols)
+            .field("has_tee", &self.tee.is_some())
+            .finish()
+    }
+}
+
+impl Default for RawTextReaderBuilder {
+    fn default() -> Self {
+        RawTextReaderBuilder::new()
+    }
+}
+
+impl<I: Iterator<Item = IonResult<String>>> RawTextReader<LineIteratorSource<I>> {
+    /// Constructs a reader that pulls lines of Ion text from the provided iterator as they are
+    /// needed. See [LineIteratorSource] for details.
+    pub fn from_lines(lines: I) -> RawTextReader<LineIteratorSource<I>> {
+        RawTextReader::new(LineIteratorSource::new(lines))
+    }
+}
+
+impl<T: TextIonDataSource> RawTextReader<T> {
+    pub fn new(input: T) -> RawTextReader<T> {
+        RawTextReaderBuilder::new().build(input)
+    }
 
     pub fn bytes_read(&self) -> usize {
         self.bytes_read
     }
 
-    fn load_next_value(&mut self) -> IonResult<()> {
-        // If the reader's current value is the beginning of a container and the user calls `next()`,
-        // we need to skip the entire container. We can do this by stepping into and then out of
-        // that container; `step_out()` has logic that will exhaust the remaining values.
-        let need_to_skip_container = self
-            .current_value
-            .as_ref()
-            .map(|v| v.value().ion_type().is_container())
-            .unwrap_or(false);
+    /// Consumes the reader, returning the underlying source so that it can be reused or cleaned up.
+    ///
+    /// The reader pulls text from its source a line at a time, so the source will usually have
+    /// been advanced past the reader's current position. Any text that has been loaded into the
+    /// reader's buffer but not yet read, including the rest of the current line, is lost; it is
+    /// not returned to the source.
+    pub fn into_source(self) -> T::TextSource {
+        self.buffer.into_input()
+    }
 
-        if need_to_skip_container {
-            self.step_in()?;
-            self.step_out()?;
+    // Allows a wrapper that feeds the reader its text (like the async reader) to reach the source
+    // without giving up the reader.
+    #[cfg(feature = "async")]
+    pub(crate) fn source_mut(&mut self) -> &mut T::TextSource {
+        self.buffer.input_mut()
+    }
+
+    /// Returns counts of the scalars read, containers entered, and bytes and lines of input
+    /// consumed so far. This is useful for reporting progress while reading a large stream.
+    pub fn stats(&self) -> ReaderStats {
+        let mut bytes_consumed = self.bytes_read;
+        let mut lines_consumed = self.buffer.lines_consumed();
+        if self.is_eof {
+            // The text that was parsed at EOF is never marked as read in the buffer, but it has
+            // been consumed all the same.
+            let unconsumed_length = self.buffer.bytes_loaded() - self.bytes_read;
+            let unconsumed_text = &self.buffer.remaining_text()[..unconsumed_length];
+            bytes_consumed += unconsumed_length;
+            lines_consumed += unconsumed_text.matches('\n').count();
         }
+        ReaderStats {
+            bytes_consumed,
+            lines_consumed,
+            ..self.stats
+        }
+    }
 
-        // Unset variables holding onto information about the previous position.
-        self.current_ivm = None;
-        self.current_value = None;
-        self.current_field_name = None;
+    /// Returns the (major, minor) version specified by the most recent Ion Version Marker the
+    /// reader has encountered. Before any IVM has been read, this returns (1, 0).
+    pub fn current_ion_version(&self) -> (u8, u8) {
+        self.ion_version
+    }
 
-        if self.parents.is_empty() {
-            // The `parents` stack is empty. We're at the top level.
+    /// If the reader is positioned over an integer, decimal, or float, returns whether its text
+    /// began with an explicit sign. Otherwise, returns None.
+    pub fn numeric_sign(&self) -> Option<Sign> {
+        match self.ion_type()? {
+            IonType::Integer | IonType::Decimal | IonType::Float => {}
+            _ => return None,
+        }
+        let sign = match self.current_value_text()?.chars().next() {
+            Some('-') => Sign::Explicit(Polarity::Negative),
+            Some('+') => Sign::Explicit(Polarity::Positive),
+            _ => Sign::Implicit,
+        };
+        Some(sign)
+    }
 
-            // If the reader has already found EOF (the end of the top level), there's no need to
-            // try to read more data. Return Ok(None).
-            if self.is_eof {
-                self.current_value = None;
-                return Ok(());
-            }
+    /// If the reader is positioned over a non-null timestamp, returns the timestamp's text exactly
+    /// as it appeared in the input (e.g. `2021-01-01T00:00:00.500-05:00`). Otherwise, returns
+    /// `None`. Ion timestamps have many equivalent representations (`2021T` and `2021-01-01T`
+    /// differ only in precision, and offsets can be written as `Z` or `+00:00`), so this is useful
+    /// for round-tripping a timestamp without changing how it was written. Annotations and any
+    /// surrounding whitespace or delimiters are not included.
+    pub fn timestamp_source_text(&self) -> Option<&str> {
+        if !matches!(
+            self.current_value.as_ref().map(|current| current.value()),
+            Some(TextValue::Timestamp(_))
+        ) {
+            return None;
+        }
+        let text = self.current_value_text()?;
+        // The timestamp ends at the first character that can't appear in one, whether that's
+        // whitespace, a delimiter, or the start of a comment.
+        let length = text
+            .find(|c: char| !(c.is_ascii_digit() || "-+:.TZ".contains(c)))
+            .unwrap_or(text.len());
+        Some(&text[..length])
+    }
 
-            // Otherwise, see if the next token in the stream is an Ion Version Marker.
-            if let Ok(Some(_)) = self.parse_next(ion_1_0_version_marker) {
-                // We found an IVM; we currently only support Ion 1.0.
-                self.current_ivm = Some((1, 0));
-                return Ok(());
+    /// Returns the number of bytes of input that the current value's text occupies. Annotations and
+    /// any surrounding whitespace, comments, or delimiters are not included, but syntax that is part
+    /// of the value itself is: the length of `"hello"` is 7, and the length of a blob is the length
+    /// of its base64 text and braces rather than of its decoded bytes. For a long string made of
+    /// several segments, this includes everything from the first segment's opening quotes to the
+    /// last segment's closing quotes.
+    ///
+    /// Returns `None` if the reader is not positioned over a value or if the value is a non-null
+    /// container; a container's extent is not known until its contents have been read.
+    pub fn current_value_byte_len(&self) -> Option<usize> {
+        let current = self.current_value.as_ref()?;
+        if current.ion_type().is_container() && !matches!(current.value(), TextValue::Null(_)) {
+            return None;
+        }
+        let text = self.current_value_text()?;
+        let length = match scalar(text) {
+            Ok((remaining, _value)) => text.len() - remaining.len(),
+            // Some values (e.g. numbers and symbols) can only be parsed when followed by a
+            // delimiter. If the input ended right after the value, the value is all that's left.
+            Err(_) => text.trim_end().len(),
+        };
+        Some(length)
+    }
+
+    /// Returns `true` if the reader is positioned over a symbol that was written as an operator
+    /// (e.g. `+` or `&&`) inside an s-expression. Identifiers (`foo`), symbol IDs (`$10`), and
+    /// quoted symbols (`'+'`) are not operators, even if their text is made of operator characters.
+    pub fn is_operator_symbol(&self) -> bool {
+        if !matches!(
+            self.current_value.as_ref().map(|current| current.value()),
+            Some(TextValue::Symbol(_))
+        ) {
+            return false;
+        }
+        self.current_value_text()
+            .and_then(|text| text.chars().next())
+            .map(|c| OPERATOR_CHARACTERS.contains(c))
+            .unwrap_or(false)
+    }
+
+    /// If the reader is positioned over a list, steps into it, reads each of its values as an
+    /// `i64`, and then steps out. If the list contains a value that is not a non-null integer,
+    /// returns a decoding error.
+    ///
+    /// A `null.list` is treated as a list with no values; this method will return an empty `Vec`.
+    /// If the reader is not positioned over a list, returns an illegal operation error.
+    pub fn read_i64_list(&mut self) -> IonResult<Vec<i64>> {
+        self.read_list("integers", IonType::Integer, Self::read_i64)
+    }
+
+    /// Like [read_i64_list](Self::read_i64_list), but reads each of the list's values as an `f64`.
+    /// If the list contains a value that is not a non-null float, returns a decoding error.
+    pub fn read_f64_list(&mut self) -> IonResult<Vec<f64>> {
+        self.read_list("floats", IonType::Float, Self::read_f64)
+    }
+
+    /// Like [read_i64_list](Self::read_i64_list), but reads each of the list's values as a
+    /// `String`. If the list contains a value that is not a non-null string, returns a decoding
+    /// error.
+    pub fn read_string_list(&mut self) -> IonResult<Vec<String>> {
+        self.read_list("strings", IonType::String, Self::read_string)
+    }
+
+    /// If the reader is positioned over a list or s-expression, steps into it and calls `f` once
+    /// for each of its values, then steps back out. `f` is called while the reader is positioned
+    /// over the value and may read it however it likes (including by stepping into it), but must
+    /// not advance the reader past it. Returns the values produced by `f` in order.
+    ///
+    /// A null list or s-expression is treated as a sequence with no values. If `f` returns an
+    /// error, reading stops and that error is returned. If the reader is not positioned over a
+    /// list or s-expression, returns an illegal operation error.
+    pub fn read_sequence<V>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> IonResult<V>,
+    ) -> IonResult<Vec<V>> {
+        match self.ion_type() {
+            Some(IonType::List) | Some(IonType::SExpression) if self.is_null() => {
+                return Ok(Vec::new())
+            }
+            Some(IonType::List) | Some(IonType::SExpression) => {}
+            other => {
+                return illegal_operation(format!(
+                    "Cannot read a sequence: the reader is positioned over {:?}",
+                    other
+                ))
             }
+        }
+        let mut values = Vec::new();
+        self.step_in()?;
+        while self.next()?.is_some() {
+            values.push(f(self)?);
+        }
+        self.step_out()?;
+        Ok(values)
+    }
 
-            // If it wasn't an IVM, it has to be a value.
-            let value = self.next_top_level_value();
-            match value {
-                Ok(None) => {
-                    // We hit EOF; make a note of it and clear the current value.
-                    self.is_eof = true;
-                    self.current_value = None;
-                }
-                Ok(Some(ref value)) => {
-                    // We read a value successfully; set it as our current value.
-                    // TODO: This currently clones the loaded value. This will not be necessary
-                    //       when `next()` returns an IonType instead of an AnnotatedTextValue.
-                    self.current_value = Some(value.clone());
+    /// If the reader is positioned over a struct, steps into it, materializes each of its fields'
+    /// values as an [OwnedElement], and steps back out. Returns a map from each field name to its
+    /// value; if a field name appears more than once, the last value wins.
+    ///
+    /// A null struct is treated as a struct with no fields. If the reader is not positioned over a
+    /// struct, returns an illegal operation error. Field names that were written as symbol IDs
+    /// cannot be resolved by the raw reader; encountering one also returns an illegal operation
+    /// error, leaving the reader inside the struct.
+    pub fn read_map(&mut self) -> IonResult<HashMap<String, OwnedElement>> {
+        match self.ion_type() {
+            Some(IonType::Struct) if self.is_null() => return Ok(HashMap::new()),
+            Some(IonType::Struct) => {}
+            other => {
+                return illegal_operation(format!(
+                    "Cannot read a map: the reader is positioned over {:?}",
+                    other
+                ))
+            }
+        }
+        let mut map = HashMap::new();
+        self.step_in()?;
+        while self.next()?.is_some() {
+            let field_name = match self.field_name().and_then(|name| name.text()) {
+                Some(text) => text.to_string(),
+                None => {
+                    return illegal_operation(format!(
+                        "Cannot read a map: the field name {:?} has no text",
+                        self.field_name()
+                    ))
                 }
-                _ => {}
             };
-            return Ok(());
+            map.insert(field_name, self.read_element()?);
         }
-        // Otherwise, the `parents` stack is not empty. We're inside a container.
+        self.step_out()?;
+        Ok(map)
+    }
 
-        // The `ParentLevel` type is only a couple of stack-allocated bytes. It's very cheap to clone.
-        let parent = *self.parents.last().unwrap();
-        // If the reader had already found the end of this container, return Ok(None).
-        if parent.is_exhausted() {
-            self.current_value = None;
-            return Ok(());
+    // Steps into the list under the reader, reads each of its values using `read`, and steps out.
+    // Every value in the list must be a non-null value of `expected_type`.
+    fn read_list<V>(
+        &mut self,
+        description: &str,
+        expected_type: IonType,
+        read: fn(&mut Self) -> IonResult<Option<V>>,
+    ) -> IonResult<Vec<V>> {
+        match self.ion_type() {
+            Some(IonType::List) if self.is_null() => return Ok(Vec::new()),
+            Some(IonType::List) => {}
+            other => {
+                return illegal_operation(format!(
+                    "Cannot read a list of {}: the reader is positioned over {:?}",
+                    description, other
+                ))
+            }
         }
-        // Otherwise, try to read the next value. The syntax we expect will depend on the
-        // IonType of the parent container.
-        let value = match parent.ion_type() {
-            IonType::List => self.next_list_value(),
-            IonType::SExpression => self.next_s_expression_value(),
-            IonType::Struct => {
-                // If the reader finds a field name...
-                if let Some(field_name) = self.next_struct_field_name()? {
-                    // ...remember it and return the field value that follows.
-                    self.current_field_name = Some(field_name);
-                    let field_value_result = self.next_struct_field_value()?;
-                    Ok(Some(field_value_result))
-                } else {
-                    // Otherwise, this is the end of the struct.
-                    Ok(None)
+        let mut values = Vec::new();
+        self.step_in()?;
+        while let Some(item) = self.next()? {
+            match (item, read(self)?) {
+                (RawStreamItem::Value(ion_type, false), Some(value))
+                    if ion_type == expected_type =>
+                {
+                    values.push(value)
+                }
+                (other, _) => {
+                    return decoding_error(format!(
+                        "Expected a list of {}, but found a {:?}",
+                        description, other
+                    ))
                 }
             }
-            other => unreachable!(
-                "The reader's `parents` stack contained a scalar value: {:?}",
-                other
-            ),
-        };
+        }
+        self.step_out()?;
+        Ok(values)
+    }
 
-        match value {
-            Ok(None) => {
-                // If the parser returns Ok(None), we've just encountered the end of the container for
-                // the first time. Set `is_exhausted` so we won't try to parse more until `step_out()` is
-                // called.
-                // We previously used a copy of the last `ParentLevel` in the stack to simplify reading.
-                // To modify it, we'll need to get a mutable reference to the original.
-                self.parents.last_mut().unwrap().set_exhausted(true);
-                self.current_value = None;
+    /// Advances to the next value and reads it as an `i64`. Returns `Ok(None)` if there are no more
+    /// values at the current depth. If the next value is not a non-null integer, returns a decoding
+    /// error. Any Ion version markers encountered along the way are skipped.
+    pub fn next_i64(&mut self) -> IonResult<Option<i64>> {
+        self.next_and_read(IonType::Integer, Self::read_i64)
+    }
+
+    /// Advances to the next value and reads it as an `f64`. Returns `Ok(None)` if there are no more
+    /// values at the current depth. If the next value is not a non-null float, returns a decoding
+    /// error. Any Ion version markers encountered along the way are skipped.
+    pub fn next_f64(&mut self) -> IonResult<Option<f64>> {
+        self.next_and_read(IonType::Float, Self::read_f64)
+    }
+
+    /// Advances to the next value and reads it as a `bool`. Returns `Ok(None)` if there are no more
+    /// values at the current depth. If the next value is not a non-null boolean, returns a
+    /// decoding error. Any Ion version markers encountered along the way are skipped.
+    pub fn next_bool(&mut self) -> IonResult<Option<bool>> {
+        self.next_and_read(IonType::Boolean, Self::read_bool)
+    }
+
+    /// Advances to the next value and reads it as a `String`. Returns `Ok(None)` if there are no
+    /// more values at the current depth. If the next value is not a non-null string, returns a
+    /// decoding error. Any Ion version markers encountered along the way are skipped.
+    pub fn next_string(&mut self) -> IonResult<Option<String>> {
+        self.next_and_read(IonType::String, Self::read_string)
+    }
+
+    // Advances to the next value, confirms that it is a non-null value of the expected type, and
+    // then reads it using the provided `read` method.
+    fn next_and_read<V>(
+        &mut self,
+        expected_type: IonType,
+        read: fn(&mut Self) -> IonResult<Option<V>>,
+    ) -> IonResult<Option<V>> {
+        loop {
+            match self.next()? {
+                None => return Ok(None),
+                Some(RawStreamItem::VersionMarker(_, _)) => continue,
+                Some(RawStreamItem::Value(ion_type, false)) if ion_type == expected_type => {
+                    return read(self)
+                }
+                Some(RawStreamItem::Value(ion_type, is_null)) => {
+                    return decoding_error(format!(
+                        "Expected a non-null {}, but found a {} (is_null: {})",
+                        expected_type, ion_type, is_null
+                    ))
+                }
             }
-            Ok(Some(value)) => {
-                // We successfully read a value. Set it as the current value.
-                self.current_value = Some(value);
+        }
+    }
+
+    /// If the reader is positioned over a non-null integer, returns its sign and magnitude;
+    /// otherwise, returns `Ok(None)`. Unlike decimals, Ion integers do not have a distinct negative
+    /// zero; `-0` is read as `0` and has a positive sign. Integers that do not fit in an `i64`
+    /// (which `next()` only accepts in [IntegerOverflowMode::Clamp]) are returned exactly.
+    pub fn read_int_parts(&self) -> IonResult<Option<(CoefficientSign, BigUint)>> {
+        if let Some(value) = self.out_of_range_integer() {
+            let sign = match value.sign() {
+                BigIntSign::Minus => CoefficientSign::Negative,
+                _ => CoefficientSign::Positive,
+            };
+            return Ok(Some((sign, value.magnitude().clone())));
+        }
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Integer(value)) => {
+                let sign = if *value < 0 {
+                    CoefficientSign::Negative
+                } else {
+                    CoefficientSign::Positive
+                };
+                Ok(Some((sign, BigUint::from(value.unsigned_abs()))))
             }
-            Err(e) => return Err(e),
+            _ => Ok(None),
+        }
+    }
+
+    /// If the reader is positioned over a non-null timestamp, returns the number of milliseconds
+    /// between the Unix epoch and that point in time. Any fractional seconds beyond millisecond
+    /// precision are truncated. If the reader is not positioned over a non-null timestamp, returns
+    /// `Ok(None)`.
+    ///
+    /// Timestamps that do not specify a time of day (that is, those with a precision of
+    /// [Precision::Day] or coarser) and timestamps with an unknown offset (`-00:00`) do not
+    /// identify a single point in time; this method returns an illegal operation error for both.
+    pub fn read_epoch_millis(&self) -> IonResult<Option<i64>> {
+        let timestamp = match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Timestamp(timestamp)) => timestamp,
+            _ => return Ok(None),
         };
+        if timestamp.precision() <= Precision::Day {
+            return illegal_operation(format!(
+                "cannot convert a timestamp with {:?} precision to epoch millis; a time of day \
+                is required",
+                timestamp.precision()
+            ));
+        }
+        if timestamp.offset().is_none() {
+            return illegal_operation(
+                "cannot convert a timestamp with an unknown offset to epoch millis",
+            );
+        }
+        // Timestamps store their fields in UTC
+        Ok(Some(
+            Utc.from_utc_datetime(&timestamp.date_time)
+                .timestamp_millis(),
+        ))
+    }
 
+    /// Clears the reader's current value and field name without advancing to the next value.
+    /// Afterward, `ion_type()` returns `None` and the `read_*` methods return `Ok(None)` as if the
+    /// reader were not positioned over a value; calling `next()` moves to the value that follows
+    /// the cleared one. If the current value is a container, its contents are skipped so that the
+    /// reader stays in step with the stream, which may return an error if they are malformed.
+    pub fn clear_current(&mut self) -> IonResult<()> {
+        self.skip_current_container()?;
+        self.current_value = None;
+        self.current_field_name = None;
         Ok(())
     }
 
-    /// Assumes that the reader is at the top level and attempts to parse the next value or IVM in
-    /// the stream.
-    fn next_top_level_value(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
-        match self.parse_next(top_level_value) {
-            Ok(Some(value)) => Ok(Some(value)),
-            Ok(None) => {
-                // The top level is the only depth at which EOF is legal. If we encounter an EOF,
-                // double check that the buffer doesn't actually have a value in it. See the
-                // comments in [parse_value_at_eof] for a detailed explanation of this.
-                self.parse_value_at_eof()
-            }
-            Err(e) => Err(e),
+    /// Returns a [ValueInfo] describing the value over which the reader is currently positioned,
+    /// or `None` if the reader is not positioned over a value.
+    pub fn current_info(&self) -> Option<ValueInfo> {
+        let current = self.current_value.as_ref()?;
+        Some(ValueInfo {
+            ion_type: current.ion_type(),
+            is_null: self.is_null(),
+            depth: self.depth(),
+            annotation_count: current.annotations().len(),
+            field_name: self.current_field_name.clone(),
+        })
+    }
+
+    /// Reads the current value as a boolean, distinguishing `null.bool` from values of other types.
+    /// Returns `Ok(Some(Some(value)))` for `true` and `false`, `Ok(Some(None))` for `null.bool`, and
+    /// `Ok(None)` if the reader is not positioned over a boolean.
+    pub fn read_bool_opt(&self) -> IonResult<Option<Option<bool>>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Boolean(value)) => Ok(Some(Some(*value))),
+            Some(TextValue::Null(IonType::Boolean)) => Ok(Some(None)),
+            _ => Ok(None),
         }
     }
 
-    /// Assumes that the reader is inside a list and attempts to parse the next value.
-    /// If the next token in the stream is an end-of-list delimiter (`]`), returns Ok(None).
-    fn next_list_value(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
-        self.parse_expected("a list", list_value_or_end)
+    /// Reads the current value as an instance of `V`. See [IonDeserialize] for details.
+    pub fn try_read_typed<V: IonDeserialize>(&mut self) -> IonResult<V> {
+        V::deserialize(self)
     }
 
-    /// Assumes that the reader is inside an s-expression and attempts to parse the next value.
-    /// If the next token in the stream is an end-of-s-expression delimiter (`)`), returns Ok(None).
-    fn next_s_expression_value(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
-        self.parse_expected("an s-expression", s_expression_value_or_end)
+    /// If the reader is positioned over a non-null symbol, returns its text as an `Arc<str>`.
+    /// The reader keeps a cache of the symbol text it has returned; reading the same symbol text
+    /// again will return a pointer to the same allocation rather than a new copy.
+    ///
+    /// The cache holds a reference to every distinct text it has returned, keeping it allocated
+    /// even after the caller has dropped its copies. To bound this cost, the cache is discarded
+    /// at each IVM and whenever it holds the maximum number of texts configured with
+    /// [RawTextReaderBuilder::with_max_interned_symbols]. After the cache is discarded, reading a
+    /// symbol text again returns a new allocation.
+    ///
+    /// Only this method uses the cache. [RawReader::read_symbol] returns a [RawSymbolToken] that
+    /// owns its own copy of the text, so calling it does not intern the text or return interned
+    /// text.
+    ///
+    /// If the reader is not positioned over a non-null symbol, returns `Ok(None)`. If the symbol
+    /// was written as a symbol ID (e.g. `$10`), its text is not known to the raw reader and this
+    /// method will return an illegal operation error.
+    pub fn read_interned_symbol(&mut self) -> IonResult<Option<Arc<str>>> {
+        let text = match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Symbol(RawSymbolToken::Text(text))) => text.as_str(),
+            Some(TextValue::Symbol(RawSymbolToken::SymbolId(sid))) => {
+                return illegal_operation(format!(
+                    "Cannot intern the text of symbol ID ${}; its text is not known.",
+                    sid
+                ))
+            }
+            _ => return Ok(None),
+        };
+        if let Some(interned) = self.interned_symbols.get(text) {
+            return Ok(Some(Arc::clone(interned)));
+        }
+        let interned: Arc<str> = Arc::from(text);
+        if self.interned_symbols.len() >= self.max_interned_symbols {
+            self.interned_symbols.clear();
+        }
+        self.interned_symbols.insert(Arc::clone(&interned));
+        Ok(Some(interned))
     }
 
-    /// Assumes that the reader is inside an struct and attempts to parse the next field name.
-    /// If the next token in the stream is an end-of-struct delimiter (`}`), returns Ok(None).
-    fn next_struct_field_name(&mut self) -> IonResult<Option<RawSymbolToken>> {
-        // If there isn't another value, this returns Ok(None).
-        self.parse_expected("a struct field name", struct_field_name_or_end)
+    /// If the reader is positioned over a non-null blob or clob, returns its [IonType] and a slice
+    /// containing its decoded bytes. Otherwise, returns `Ok(None)`.
+    pub fn read_lob_bytes(&self) -> IonResult<Option<(IonType, &[u8])>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Blob(ref value)) => Ok(Some((IonType::Blob, value.as_slice()))),
+            Some(TextValue::Clob(_)) | Some(TextValue::UnescapedClob(_)) => {
+                Ok(self.clob_bytes().map(|bytes| (IonType::Clob, bytes)))
+            }
+            _ => Ok(None),
+        }
     }
 
-    /// Assumes that the reader is inside a struct AND that a field has already been successfully
-    /// parsed from input using [next_struct_field_name] and attempts to parse the next value.
-    /// In this input position, only a value (or whitespace/comments) are legal. Anything else
-    /// (including EOF) will result in a decoding error.
-    fn next_struct_field_value(&mut self) -> IonResult<AnnotatedTextValue> {
-        // Only called after a call to [next_struct_field_name] that returns Some(field_name).
-        // It is not legal for a field name to be followed by a '}' or EOF.
-        // If there isn't another value, returns an Err.
-        self.parse_expected("a struct field value", struct_field_value)
+    /// If the reader is positioned over a non-null blob or clob, returns its decoded bytes
+    /// re-encoded as a base64 String. Otherwise, returns `Ok(None)`. This is convenient when
+    /// converting lobs to JSON, which has no binary type.
+    pub fn read_lob_base64(&self) -> IonResult<Option<String>> {
+        Ok(self
+            .read_lob_bytes()?
+            .map(|(_ion_type, bytes)| base64::encode(bytes)))
     }
 
-    /// Attempts to parse the next entity from the stream using the provided parser.
-    /// Returns a decoding error if EOF is encountered while parsing.
-    /// If the parser encounters an error, it will be returned as-is.
-    fn parse_expected<P, O>(&mut self, entity_name: &str, parser: P) -> IonResult<O>
-    where
-        P: Fn(&str) -> IResult<&str, O>,
-    {
-        match self.parse_next(parser) {
-            Ok(Some(value)) => Ok(value),
-            Ok(None) => decoding_error(format!(
-                "Unexpected end of input while reading {} on line {}: '{}'",
-                entity_name,
-                self.buffer.lines_loaded(),
-                self.buffer.remaining_text()
-            )),
-            Err(e) => decoding_error(format!(
-                "Parsing error occurred while parsing {} near line {}:\n'{}'\n{}",
-                entity_name,
-                self.buffer.lines_loaded(),
-                self.buffer.remaining_text(),
-                e
-            )),
+    // If the current value is a clob, returns its bytes. Clobs without escape sequences are
+    // borrowed directly from the text buffer.
+    fn clob_bytes(&self) -> Option<&[u8]> {
+        match self.current_value.as_ref().map(|current| current.value())? {
+            TextValue::Clob(value) => Some(value.as_slice()),
+            TextValue::UnescapedClob(length) => {
+                // The value's text begins with the clob's opening `{{"`.
+                let text = self.current_value_text()?;
+                let (text, _) = whitespace_or_comments(text).ok()?;
+                const OPENING_DELIMITER_LENGTH: usize = 3;
+                Some(&text.as_bytes()[OPENING_DELIMITER_LENGTH..OPENING_DELIMITER_LENGTH + length])
+            }
+            _ => None,
         }
     }
 
-    /// Attempts to parse the next entity from the stream using the provided parser.
-    /// If there isn't enough data in the buffer for the parser to match its input conclusively,
-    /// more data will be loaded into the buffer and the parser will be called again.
-    /// If EOF is encountered, returns `Ok(None)`.
-    fn parse_next<P, O>(&mut self, parser: P) -> IonResult<Option<O>>
-    where
-        P: Fn(&str) -> IResult<&str, O>,
-    {
-        if self.is_eof {
-            return Ok(None);
+    /// Reads all of the values that follow the reader's current position at the current depth,
+    /// materializing each of them (along with any values nested inside them) as an [OwnedElement].
+    /// When this method returns, the reader will be at the end of the current container and ready
+    /// for `step_out()` to be called. If the reader is at the top level, it will read to the end of
+    /// the stream.
+    pub fn read_remaining_elements(&mut self) -> IonResult<Vec<OwnedElement>> {
+        let mut elements = Vec::new();
+        while let Some(item) = self.next()? {
+            // Version markers can only appear at the top level and are not values; skip them.
+            if let RawStreamItem::Value(_, _) = item {
+                elements.push(self.read_element()?);
+            }
         }
+        Ok(elements)
+    }
 
-        let value = 'parse: loop {
-            // Note the number of bytes currently in the text buffer
-            let length_before_parse = self.buffer.remaining_text().len();
-            let input_text = self.buffer.remaining_text();
-            // Invoke the top_level_value() parser; this will attempt to recognize the next value
-            // in the stream and return a &str slice containing the remaining, not-yet-parsed text.
-            match parser(input_text) {
-                // If `top_level_value` returns 'Incomplete', there wasn't enough text in the buffer
-                // to match the next value. No syntax errors have been encountered (yet?), but we
-                // need to load more text into the buffer before we try to parse it again.
-                Err(Incomplete(_needed)) => {
-                    // Ask the buffer to load another line of text.
-                    // TODO: Currently this loads a single line at a time for easier testing.
-                    //       We may wish to bump it to a higher number of lines at a time (8?)
-                    //       for efficiency once we're confident in the correctness.
-                    if self.buffer.load_next_line()? == 0 {
-                        // If load_next_line() returns Ok(0), we've reached the end of our input.
-                        self.is_eof = true;
-                        // The buffer had an `Incomplete` value in it; now that we know we're at EOF,
-                        // we can determine whether the buffer's contents should actually be
-                        // considered complete.
-                        return Ok(None);
-                    }
-                    continue;
+    /// Reads all of the values that follow the reader's current position at the current depth and
+    /// returns them re-serialized as pretty-printed Ion text: each top-level value begins on a new
+    /// line and each nested value is on its own line, indented to reflect its depth. This consumes
+    /// the rest of the stream (or the rest of the current container). Ion version markers are not
+    /// included in the output. Symbols written as symbol IDs cannot be resolved by the raw reader
+    /// and are rendered as symbols with the text `$ID`.
+    pub fn to_pretty_string(&mut self) -> IonResult<String> {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output).with_pretty_print(true);
+        self.write_remaining_values(&mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        String::from_utf8(output)
+            .map_err(|e| decoding_error_raw(format!("Rendered text was not valid UTF-8: {}", e)))
+    }
+
+    // Writes each of the values that follow the reader's current position at the current depth to
+    // `writer`, stepping into containers to write their contents.
+    fn write_remaining_values<W: Write>(&mut self, writer: &mut TextWriter<W>) -> IonResult<()> {
+        while let Some(item) = self.next()? {
+            if let RawStreamItem::VersionMarker(_, _) = item {
+                continue;
+            }
+            if let Some(field_name) = self.field_name() {
+                // The writer writes field names as-is, so they are quoted here. Like annotations
+                // and symbol values, symbol IDs are written unquoted.
+                let field_name = match field_name {
+                    RawSymbolToken::Text(text) => quote_symbol_if_needed(text).into_owned(),
+                    RawSymbolToken::SymbolId(sid) => format!("${}", sid),
+                };
+                writer.set_field_name(&field_name);
+            }
+            writer.set_annotation_tokens(self.annotations());
+            match self.current_value.as_ref().map(|current| current.value()) {
+                Some(TextValue::ListStart)
+                | Some(TextValue::SExpressionStart)
+                | Some(TextValue::StructStart) => {
+                    let ion_type = self.ion_type().unwrap();
+                    writer.step_in(ion_type)?;
+                    self.step_in()?;
+                    self.write_remaining_values(writer)?;
+                    self.step_out()?;
+                    writer.step_out()?;
                 }
-                Ok((remaining_text, value)) => {
-                    // Our parser successfully matched a value.
-                    // Note the length of the text that remains after parsing.
-                    let length_after_parse = remaining_text.len();
-                    // The difference in length tells us how many bytes were part of the
-                    // text representation of the value that we found.
-                    let bytes_consumed = length_before_parse - length_after_parse;
-                    // Discard `bytes_consumed` bytes from the TextBuffer.
-                    self.buffer.consume(bytes_consumed);
-                    self.bytes_read += bytes_consumed;
-                    // Break out of the read/parse loop, returning the value that we matched.
-                    break 'parse value;
+                Some(TextValue::Null(ion_type)) => writer.write_null(*ion_type)?,
+                Some(TextValue::Boolean(value)) => writer.write_bool(*value)?,
+                Some(TextValue::Integer(value)) => match self.out_of_range_integer() {
+                    Some(value) => writer.write_big_int(&value)?,
+                    None => writer.write_i64(*value)?,
+                },
+                Some(TextValue::Float(value)) => writer.write_f64(*value)?,
+                Some(TextValue::Decimal(value)) => writer.write_decimal(value)?,
+                Some(TextValue::Timestamp(value)) => writer.write_timestamp(value)?,
+                Some(TextValue::String(value)) => writer.write_string(value)?,
+                Some(TextValue::Symbol(value)) => writer.write_symbol_token(value)?,
+                Some(TextValue::Blob(value)) => writer.write_blob(value)?,
+                Some(TextValue::Clob(_)) | Some(TextValue::UnescapedClob(_)) => {
+                    writer.write_clob(self.clob_bytes().unwrap_or_default())?
                 }
-                Err(e) => {
-                    // Return an error that contains the text currently in the buffer (i.e. what we
-                    // were attempting to parse with `top_level_value`.)
-                    // TODO: We probably don't want to surface the nom error (`e`) directly, but it's
-                    //       useful for debugging.
-                    return decoding_error(format!(
-                        "Parsing error occurred near line {}: '{}': '{}'",
-                        self.buffer.lines_loaded(),
-                        self.buffer.remaining_text(),
-                        e
-                    ));
+                None => unreachable!("next() returned a value but there is no current value"),
+            }
+        }
+        Ok(())
+    }
+
+    // Materializes the value over which the reader is currently positioned as an OwnedElement.
+    // If the value is a container, this steps into it, reads all of its children, and steps out.
+    pub(crate) fn read_element(&mut self) -> IonResult<OwnedElement> {
+        let annotations = self.annotations().iter().map(owned_symbol_token).collect();
+        let value = match (self.ion_type(), self.is_null()) {
+            (Some(IonType::List), false) => {
+                OwnedValue::List(OwnedSequence::new(self.read_child_elements()?))
+            }
+            (Some(IonType::SExpression), false) => {
+                OwnedValue::SExpression(OwnedSequence::new(self.read_child_elements()?))
+            }
+            (Some(IonType::Struct), false) => {
+                self.step_in()?;
+                let mut fields = Vec::new();
+                while self.next()?.is_some() {
+                    let field_name = self
+                        .field_name()
+                        .map(owned_symbol_token)
+                        .expect("No field name found for a value inside a struct.");
+                    fields.push((field_name, self.read_element()?));
                 }
-            };
+                self.step_out()?;
+                OwnedValue::Struct(fields.into_iter().collect())
+            }
+            _ => self.read_scalar_value()?,
         };
+        Ok(OwnedElement::new(annotations, value))
+    }
 
-        Ok(Some(value))
+    // Steps into the current container, materializes each of its children, and steps back out.
+    fn read_child_elements(&mut self) -> IonResult<Vec<OwnedElement>> {
+        self.step_in()?;
+        let elements = self.read_remaining_elements()?;
+        self.step_out()?;
+        Ok(elements)
     }
 
-    // Parses the contents of the text buffer again with the knowledge that we're at the end of the
-    // input stream. This allows us to resolve a number of ambiguous cases.
-    // For a detailed description of the problem that this addresses, please see:
-    // https://github.com/amzn/ion-rust/issues/318
-    // This method should only be called when the reader is at the top level. An EOF at any other
-    // depth is an error.
-    fn parse_value_at_eof(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
-        // An arbitrary, cheap-to-parse Ion value that we append to the buffer when its contents at
-        // EOF are ambiguous.
-        const SENTINEL_ION_TEXT: &str = "\n0\n";
-        // Make a note of the buffer's length; we're about to modify it.
-        let original_length = self.buffer.remaining_text().len();
-        // Append our sentinel value to the end of the input buffer.
-        self.buffer.inner().push_str(SENTINEL_ION_TEXT);
-        // If the buffer contained a value, the newline will indicate that the contents of the
-        // buffer were complete. For example:
-        // * the integer `7` becomes `7\n`; it wasn't the first digit in a truncated `755`.
-        // * the boolean `true` becomes `false\n`; it wasn't actually half of the
-        //   identifier `falseTeeth`.
-        //
-        // If the buffer contained a value that's written in segments, the extra `0` will indicate
-        // that no more segments are coming. For example:
-        // * `foo::bar` becomes `foo::bar\n0\n`; the parser can see that 'bar' is a value, not
-        //    another annotation in the sequence.
-        // * `'''long-form string'''` becomes `'''long-form string'''\n0\n`; the parser can see that
-        //   there aren't any more long-form string segments in the sequence.
-        //
-        // Attempt to parse the updated buffer.
-        let value = match top_level_value(self.buffer.remaining_text()) {
-            Ok(("\n", value))
-                if value.annotations().is_empty() && *value.value() == TextValue::Integer(0) =>
-            {
-                // We found the unannotated zero that we appended to the end of the buffer.
-                // The "\n" in this pattern is the unparsed text left in the buffer,
-                // which indicates that our 0 was parsed.
-                Ok(None)
+    // Converts the current scalar value (or null) into an OwnedValue.
+    fn read_scalar_value(&self) -> IonResult<OwnedValue> {
+        let value = match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Null(ion_type)) => OwnedValue::Null(*ion_type),
+            Some(TextValue::Boolean(value)) => OwnedValue::Boolean(*value),
+            Some(TextValue::Integer(value)) => match self.out_of_range_integer() {
+                Some(value) => OwnedValue::Integer(AnyInt::BigInt(value)),
+                None => OwnedValue::Integer(AnyInt::I64(*value)),
+            },
+            Some(TextValue::Float(value)) => OwnedValue::Float(*value),
+            Some(TextValue::Decimal(value)) => OwnedValue::Decimal(value.clone()),
+            Some(TextValue::Timestamp(value)) => OwnedValue::Timestamp(value.clone()),
+            Some(TextValue::String(value)) => OwnedValue::String(value.clone()),
+            Some(TextValue::Symbol(value)) => OwnedValue::Symbol(owned_symbol_token(value)),
+            Some(TextValue::Blob(value)) => OwnedValue::Blob(value.clone()),
+            Some(TextValue::Clob(_)) | Some(TextValue::UnescapedClob(_)) => {
+                OwnedValue::Clob(self.clob_bytes().unwrap_or_default().to_vec())
             }
-            Ok((_remaining_text, value)) => {
-                // We found something else. The zero is still in the buffer; we can leave it there.
-                // The reader's `is_eof` flag has been set, so the text buffer will never be used
-                // again. Return the value we found.
-                Ok(Some(value))
+            Some(TextValue::ListStart)
+            | Some(TextValue::SExpressionStart)
+            | Some(TextValue::StructStart)
+            | None => {
+                return illegal_operation("The reader is not positioned over a scalar value.")
             }
-            Err(Incomplete(_needed)) => {
-                decoding_error(format!(
-                    "Unexpected end of input on line {}: '{}'",
-                    self.buffer.lines_loaded(),
-                    &self.buffer.remaining_text()[..original_length] // Don't show the extra `\n0\n`
-                ))
+        };
+        Ok(value)
+    }
+
+    /// If the reader is positioned over an integer, returns the text of its annotations along with
+    /// its value; otherwise, returns `Ok(None)`. Annotations written as symbol IDs are rendered
+    /// as `$ID`. Like [read_i64](RawReader::read_i64), this returns an error if the integer does
+    /// not fit in an `i64`.
+    pub fn read_i64_annotated(&self) -> IonResult<Option<(Vec<String>, i64)>> {
+        if let Some(text) = self.out_of_range_integer_text() {
+            return self.integer_overflow_error(text);
+        }
+        self.read_annotated(|value| match value {
+            TextValue::Integer(value) => Some(*value),
+            _ => None,
+        })
+    }
+
+    // Applies `read` to the current value; if it returns a value, pairs it with the text of the
+    // current value's annotations.
+    fn read_annotated<V>(
+        &self,
+        read: impl FnOnce(&TextValue) -> Option<V>,
+    ) -> IonResult<Option<(Vec<String>, V)>> {
+        let current = match self.current_value.as_ref() {
+            Some(current) => current,
+            None => return Ok(None),
+        };
+        let value = match read(current.value()) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let annotations = current
+            .annotations()
+            .iter()
+            .map(symbol_token_text)
+            .collect();
+        Ok(Some((annotations, value)))
+    }
+
+    /// Reads the rest of the stream, returning a map from each distinct annotation found on a
+    /// top-level value to the indices of the top-level values that bear it, in ascending order.
+    /// Indices count the top-level values that follow the reader's current position, starting
+    /// at zero; IVMs are not counted. For example, indexing `a::1 b::2 a::3` produces
+    /// `{"a": [0, 2], "b": [1]}`. Annotations on nested values are ignored, and annotations
+    /// written as symbol IDs are indexed as the text `$ID`.
+    ///
+    /// If the reader is not at the top level, returns an illegal operation error.
+    pub fn index_by_annotation(&mut self) -> IonResult<HashMap<String, Vec<usize>>> {
+        if self.depth() > 0 {
+            return illegal_operation("index_by_annotation() can only be called at the top level");
+        }
+        let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut value_index = 0;
+        while let Some(item) = self.next()? {
+            if let RawStreamItem::VersionMarker(_, _) = item {
+                continue;
             }
-            Err(e) => {
-                decoding_error(format!(
-                    "Parsing error occurred near line {}: '{}': '{}'",
-                    self.buffer.lines_loaded(),
-                    &self.buffer.remaining_text()[..original_length], // Don't show the extra `\n0\n`
-                    e
-                ))
+            for annotation in self.annotations() {
+                let indices = index.entry(symbol_token_text(annotation)).or_default();
+                // A value can repeat an annotation (`a::a::1`); only index it once.
+                if indices.last() != Some(&value_index) {
+                    indices.push(value_index);
+                }
+            }
+            value_index += 1;
+        }
+        Ok(index)
+    }
+
+    /// Reads the next top-level value, verifying that it is a struct and that it is the only value
+    /// in the rest of the document, then steps into it. This is useful for file formats that wrap
+    /// their contents in a single top-level struct. Any IVMs that precede the struct are skipped.
+    ///
+    /// To confirm that nothing follows the struct, this loads the remainder of the document into
+    /// the reader's buffer. If the next value is not a non-null struct or if other values follow
+    /// it, returns a decoding error. If the reader is not at the top level, returns an illegal
+    /// operation error.
+    pub fn read_single_struct(&mut self) -> IonResult<()> {
+        if self.depth() > 0 {
+            return illegal_operation("read_single_struct() can only be called at the top level");
+        }
+        let item = loop {
+            match self.next()? {
+                Some(RawStreamItem::VersionMarker(_, _)) => continue,
+                item => break item,
             }
         };
+        match item {
+            Some(RawStreamItem::Value(IonType::Struct, false)) => {}
+            Some(RawStreamItem::Value(ion_type, is_null)) => {
+                return decoding_error(format!(
+                    "Expected the document to be a single struct, but found a {}{:?} near line {}.",
+                    if is_null { "null " } else { "" },
+                    ion_type,
+                    self.current_line()
+                ));
+            }
+            _ => {
+                return decoding_error(
+                    "Expected the document to be a single struct, but it had no values.",
+                )
+            }
+        }
+        self.step_in()?;
+        let end = self.find_container_end()?;
+        while self.load_next_line()? > 0 {}
+        // Skip the struct's closing `}`
+        if !self.buffer.is_blank_after(end + 1) {
+            return decoding_error(format!(
+                "Expected the document to be a single struct, but found more data after the \
+                struct that ends near line {}.",
+                self.current_line()
+            ));
+        }
+        Ok(())
+    }
 
-        // If we didn't consume the sentinel value, remove the sentinel value from the buffer.
-        // Doing so makes this method idempotent.
-        if self.buffer.remaining_text().ends_with(SENTINEL_ION_TEXT) {
-            let length = self.buffer.remaining_text().len();
-            self.buffer
-                .inner()
-                .truncate(length - SENTINEL_ION_TEXT.len());
+    /// Returns an error if the current value's annotations are not exactly `expected`, in order.
+    /// This is useful for validating tagged values like `timestamp::...`. A mismatch produces a
+    /// decoding error naming both the expected and the actual annotations; annotations written as
+    /// symbol IDs cannot be resolved by the raw reader and are compared as the text `$ID`. If the
+    /// reader is not positioned over a value, returns an illegal operation error.
+    pub fn expect_annotations(&self, expected: &[&str]) -> IonResult<()> {
+        let current = match self.current_value.as_ref() {
+            Some(current) => current,
+            None => {
+                return illegal_operation(
+                    "Cannot check annotations: the reader is not positioned on a value.",
+                )
+            }
+        };
+        let actual: Vec<String> = current
+            .annotations()
+            .iter()
+            .map(symbol_token_text)
+            .collect();
+        if actual
+            .iter()
+            .map(String::as_str)
+            .eq(expected.iter().copied())
+        {
+            return Ok(());
         }
+        decoding_error(format!(
+            "Expected the annotations {:?} near line {}, but found {:?}.",
+            expected,
+            self.current_line(),
+            actual
+        ))
+    }
 
-        value
+    /// If the reader is positioned over a scalar value (including a null), returns that value
+    /// rendered in its Ion text form. Strings and symbols are returned as their text without any
+    /// surrounding quotes; symbols written as symbol IDs are rendered as `$ID`. If the reader is
+    /// positioned over a container or is not positioned over a value, returns `Ok(None)`.
+    pub fn read_as_string(&self) -> IonResult<Option<String>> {
+        let text = match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Null(ion_type)) => render(|writer| writer.write_null(*ion_type))?,
+            Some(TextValue::Boolean(value)) => value.to_string(),
+            Some(TextValue::Integer(value)) => match self.out_of_range_integer() {
+                Some(value) => value.to_string(),
+                None => value.to_string(),
+            },
+            Some(TextValue::Float(value)) => render(|writer| writer.write_f64(*value))?,
+            Some(TextValue::Decimal(value)) => decimal_text(value),
+            Some(TextValue::Timestamp(value)) => render(|writer| writer.write_timestamp(value))?,
+            Some(TextValue::String(value)) => value.clone(),
+            Some(TextValue::Symbol(RawSymbolToken::Text(text))) => text.clone(),
+            Some(TextValue::Symbol(RawSymbolToken::SymbolId(sid))) => format!("${}", sid),
+            Some(TextValue::Blob(value)) => render(|writer| writer.write_blob(value))?,
+            Some(TextValue::Clob(_)) | Some(TextValue::UnescapedClob(_)) => {
+                let bytes = self.clob_bytes().unwrap_or_default();
+                render(|writer| writer.write_clob(bytes))?
+            }
+            Some(TextValue::ListStart)
+            | Some(TextValue::SExpressionStart)
+            | Some(TextValue::StructStart)
+            | None => return Ok(None),
+        };
+        Ok(Some(text))
     }
-}
 
-// Returned by the `annotations()` method below if there is no current value.
-const EMPTY_SLICE_RAW_SYMBOL_TOKEN: &[RawSymbolToken] = &[];
+    /// Returns an iterator over the remaining fields in the struct that the reader is currently
+    /// inside, yielding each field's name and value together. Field names that were written as
+    /// symbol IDs cannot be resolved by the raw reader and are returned as `None`.
+    ///
+    /// The iterator ends when it reaches the end of the struct; it does not step out. If the reader
+    /// is not inside a struct, the iterator will yield a single illegal operation error.
+    pub fn struct_entries(
+        &mut self,
+    ) -> impl Iterator<Item = IonResult<(Option<String>, RawStreamItem)>> + '_ {
+        let mut is_done = false;
+        std::iter::from_fn(move || {
+            if is_done {
+                return None;
+            }
+            if !self.in_struct() {
+                is_done = true;
+                return Some(illegal_operation(
+                    "Cannot iterate over struct entries: the reader is not inside a struct.",
+                ));
+            }
+            match self.next() {
+                Ok(Some(item)) => {
+                    let field_name = self
+                        .field_name()
+                        .and_then(|name| name.text())
+                        .map(|text| text.to_string());
+                    Some(Ok((field_name, item)))
+                }
+                Ok(None) => {
+                    is_done = true;
+                    None
+                }
+                Err(e) => {
+                    is_done = true;
+                    Some(Err(e))
+                }
+            }
+        })
+    }
 
-// TODO: This implementation of the text reader eagerly materializes each value that it encounters
-//       in the stream and stores it in the reader as `current_value`. Each time a user requests
-//       a value via `read_i64`, `read_bool`, etc, a clone of `current_value` is returned (assuming
-//       its type is in alignment with the request).
-//       A better implementation would identify the input slice containing the next value without
-//       materializing it and then attempt to materialize it when the user calls `read_TYPE`. This
-//       would take less memory and would only materialize values that the user requests.
-//       See: https://github.com/amzn/ion-rust/issues/322
-impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
-    fn ion_version(&self) -> (u8, u8) {
-        // TODO: The text reader does not yet have IVM support
-        (1, 0)
+    /// Returns the current value's annotations exactly as they were stored when the value was
+    /// parsed, without resolving or converting them. This is equivalent to
+    /// [RawReader::annotations], but does not require the trait to be in scope. If there is no
+    /// current value or it has no annotations, returns an empty slice.
+    pub fn annotations_raw(&self) -> &[RawSymbolToken] {
+        RawReader::annotations(self)
     }
 
-    fn next(&mut self) -> IonResult<Option<RawStreamItem>> {
-        // Parse the next value from the stream, storing it in `self.current_value`.
-        self.load_next_value()?;
+    /// Looks at the Ion type and null-ness of the next value and advances to it only if `pred`
+    /// returns `true`, returning the same item that `next()` would have. If `pred` returns `false`,
+    /// returns `Ok(None)` and leaves the reader positioned before that value, so that the next call
+    /// to `next()` or `next_if()` will encounter it again. While positioned before the value, the
+    /// reader has no current value.
+    ///
+    /// `pred` is only consulted for values; Ion version markers and the end of the current
+    /// container are always advanced past, as they would be by `next()`.
+    pub fn next_if(
+        &mut self,
+        pred: impl FnOnce(IonType, bool) -> bool,
+    ) -> IonResult<Option<RawStreamItem>> {
+        match self.advance()? {
+            Some(RawStreamItem::Value(ion_type, is_null)) if !pred(ion_type, is_null) => {
+                // The previous value (if any) has already been moved past, so there's nothing to
+                // restore. Set the new value aside until the next call to `next()`.
+                let value = self.current_value.take().unwrap();
+                let field_name = self.current_field_name.take();
+                self.deferred_value = Some((value, field_name, self.current_value_range.clone()));
+                Ok(None)
+            }
+            item => {
+                self.count_scalar(&item);
+                Ok(item)
+            }
+        }
+    }
+
+    // Moves the reader to the next item in the stream without updating `stats.scalars_read`;
+    // values are only counted once `next()` or `next_if()` returns them.
+    fn advance(&mut self) -> IonResult<Option<RawStreamItem>> {
+        if let Some((value, field_name, range)) = self.deferred_value.take() {
+            // `next_if()` has already parsed the next value; it only needs to be restored.
+            self.current_value = Some(value);
+            self.current_field_name = field_name;
+            self.current_value_range = range;
+        } else {
+            // Parse the next value from the stream, storing it in `self.current_value`.
+            let result = self.load_next_value();
+            self.last_parse_failed = result.is_err();
+            result?;
+        }
 
         // If we're positioned on an IVM, return the (major, minor) version tuple
         if let Some((major, minor)) = self.current_ivm {
+            // An IVM resets the symbol context, so symbols seen before it are unlikely to repeat.
+            self.interned_symbols.clear();
             return Ok(Some(RawStreamItem::VersionMarker(major, minor)));
         }
 
@@ -409,165 +1265,301 @@ impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
         }
     }
 
-    fn ion_type(&self) -> Option<IonType> {
-        self.current_value.as_ref().map(|v| v.ion_type())
+    fn count_scalar(&mut self, item: &Option<RawStreamItem>) {
+        if matches!(item, Some(RawStreamItem::Value(ion_type, _)) if !ion_type.is_container()) {
+            self.stats.scalars_read += 1;
+        }
     }
 
-    fn is_null(&self) -> bool {
-        self.current_value
-            .as_ref()
-            .map(|v| matches!(v.value(), TextValue::Null(_)))
-            .unwrap_or(false)
+    /// Returns `true` if the innermost container that the reader has stepped into is a struct.
+    /// Returns `false` at the top level.
+    pub fn in_struct(&self) -> bool {
+        self.parent_type() == Some(IonType::Struct)
     }
 
-    fn annotations(&self) -> &[RawSymbolToken] {
-        self.current_value
-            .as_ref()
-            .map(|value| value.annotations())
-            .unwrap_or(EMPTY_SLICE_RAW_SYMBOL_TOKEN)
+    /// Returns `true` if the innermost container that the reader has stepped into is a list.
+    /// Returns `false` at the top level.
+    pub fn in_list(&self) -> bool {
+        self.parent_type() == Some(IonType::List)
     }
 
-    fn field_name(&self) -> Option<&RawSymbolToken> {
-        self.current_field_name.as_ref()
+    /// Returns `true` if the innermost container that the reader has stepped into is an
+    /// s-expression. Returns `false` at the top level.
+    pub fn in_sexp(&self) -> bool {
+        self.parent_type() == Some(IonType::SExpression)
     }
 
-    fn read_null(&mut self) -> IonResult<Option<IonType>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Null(ion_type)) => Ok(Some(*ion_type)),
-            _ => Ok(None),
+    // The Ion type of the innermost container that the reader has stepped into, if any.
+    fn parent_type(&self) -> Option<IonType> {
+        self.parents.last().map(|parent| parent.ion_type())
+    }
+
+    /// Advances to the next field in the struct that the reader is currently inside, returning the
+    /// field's name and the Ion type of its value. This is a borrowing alternative to
+    /// [struct_entries](Self::struct_entries): the name is borrowed from the reader rather than
+    /// copied, and the value is not read. Field names that were written as symbol IDs cannot be
+    /// resolved by the raw reader and are returned as `None`.
+    ///
+    /// Because the returned name borrows the reader, this is a method rather than an [Iterator];
+    /// once the caller is done with the name, it can use the reader to read the field's value or
+    /// `step_in()` to it before calling `next_field()` again. Returns `Ok(None)` at the end of the
+    /// struct; it does not step out. If the reader is not inside a struct, returns an illegal
+    /// operation error.
+    pub fn next_field(&mut self) -> IonResult<Option<(Option<&str>, IonType)>> {
+        if !self.in_struct() {
+            return illegal_operation(
+                "Cannot read the next field: the reader is not inside a struct.",
+            );
+        }
+        match self.next()? {
+            Some(RawStreamItem::Value(ion_type, _is_null)) => {
+                let field_name = self.field_name().and_then(|name| name.text());
+                Ok(Some((field_name, ion_type)))
+            }
+            Some(RawStreamItem::VersionMarker(_, _)) => {
+                unreachable!("Found an Ion version marker inside a struct.")
+            }
+            None => Ok(None),
         }
     }
 
-    fn read_bool(&mut self) -> IonResult<Option<bool>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Boolean(value)) => Ok(Some(*value)),
-            _ => Ok(None),
+    /// Advances through the struct that the reader is currently inside until it finds a field named
+    /// `name`, then reads that field's value as an i64. Returns `Ok(None)` if no such field follows
+    /// the reader's current position; in that case, the reader is left at the end of the struct.
+    /// Like [read_i64](RawReader::read_i64), also returns `Ok(None)` if the field's value is not a
+    /// non-null integer.
+    ///
+    /// Fields are searched in stream order, so a field that precedes the reader's current position
+    /// will not be found. If the reader is not inside a struct, returns an illegal operation error.
+    pub fn read_field_i64(&mut self, name: &str) -> IonResult<Option<i64>> {
+        self.read_field(name, Self::read_i64)
+    }
+
+    /// Like [read_field_i64](Self::read_field_i64), but reads the field's value as an `f64`.
+    pub fn read_field_f64(&mut self, name: &str) -> IonResult<Option<f64>> {
+        self.read_field(name, Self::read_f64)
+    }
+
+    /// Like [read_field_i64](Self::read_field_i64), but reads the field's value as a `bool`.
+    pub fn read_field_bool(&mut self, name: &str) -> IonResult<Option<bool>> {
+        self.read_field(name, Self::read_bool)
+    }
+
+    /// Like [read_field_i64](Self::read_field_i64), but reads the field's value as a `String`.
+    pub fn read_field_string(&mut self, name: &str) -> IonResult<Option<String>> {
+        self.read_field(name, Self::read_string)
+    }
+
+    // Advances to the field named `name` using `next_field()` and reads its value with `read`.
+    fn read_field<V>(
+        &mut self,
+        name: &str,
+        read: fn(&mut Self) -> IonResult<Option<V>>,
+    ) -> IonResult<Option<V>> {
+        loop {
+            match self.next_field()? {
+                Some((Some(field_name), _ion_type)) if field_name == name => return read(self),
+                Some(_) => continue,
+                None => return Ok(None),
+            }
         }
     }
 
-    fn read_i64(&mut self) -> IonResult<Option<i64>> {
+    /// Like [read_i64](RawReader::read_i64), but if the current value is an integer that does not
+    /// fit in an `i64`, returns [i64::MIN] or [i64::MAX] instead of an error. This is useful for
+    /// lossy ingestion. The reader must be configured with [IntegerOverflowMode::Clamp] for `next()`
+    /// to accept such integers; see [RawTextReaderBuilder::with_integer_overflow_mode].
+    pub fn read_i64_clamped(&mut self) -> IonResult<Option<i64>> {
         match self.current_value.as_ref().map(|current| current.value()) {
             Some(TextValue::Integer(value)) => Ok(Some(*value)),
             _ => Ok(None),
         }
     }
 
-    fn read_f32(&mut self) -> IonResult<Option<f32>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Float(value)) => Ok(Some(*value as f32)),
-            _ => Ok(None),
+    /// Reads the current value using `read`, unless the current value is a null of any type, in
+    /// which case `Ok(None)` is returned without calling `read`. This is useful when deserializing
+    /// nullable fields: the typed read methods return `Ok(None)` for a null of their own type but
+    /// treat a null of any other type as a type mismatch.
+    pub fn read_optional<V>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> IonResult<Option<V>>,
+    ) -> IonResult<Option<V>> {
+        if self.is_null() {
+            return Ok(None);
         }
+        read(self)
     }
 
-    fn read_f64(&mut self) -> IonResult<Option<f64>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Float(value)) => Ok(Some(*value)),
-            _ => Ok(None),
+    /// Advances the reader through the values at the current depth until it finds one that is
+    /// annotated with `annotation`, returning `Ok(Some(()))` with the reader positioned on that
+    /// value. Containers that are passed over are skipped without being stepped into, as are any
+    /// Ion version markers. If no such value follows the reader's current position, returns
+    /// `Ok(None)` and leaves the reader at the end of the current depth. The value the reader is
+    /// on when this method is called is not considered.
+    pub fn skip_until_annotation(&mut self, annotation: &str) -> IonResult<Option<()>> {
+        loop {
+            match self.next()? {
+                Some(RawStreamItem::Value(_, _)) => {
+                    if self
+                        .annotations()
+                        .iter()
+                        .any(|token| token.text() == Some(annotation))
+                    {
+                        return Ok(Some(()));
+                    }
+                }
+                Some(RawStreamItem::VersionMarker(_, _)) => continue,
+                None => return Ok(None),
+            }
         }
     }
 
-    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Decimal(ref value)) => Ok(Some(value.clone())),
-            _ => Ok(None),
-        }
+    /// Returns the number of annotations on the current value, or `0` if the reader is not on a
+    /// value. Unlike [annotations](RawReader::annotations), this does not require the caller to
+    /// inspect the annotations themselves.
+    pub fn annotation_count(&self) -> usize {
+        self.annotations().len()
     }
 
-    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
-        // TODO: This function is deprecated. Remove it from the trait.
-        unimplemented!("`read_datetime` is being removed; use `read_timestamp` instead")
+    /// Returns the text of each of the current value's annotations, in order. Annotations that
+    /// were written as symbol IDs (like `$10`) cannot be resolved by the raw reader and are
+    /// returned as `None`. Repeated annotations (`a::a::5`) are returned as many times as they
+    /// appear. If the reader is not on a value, the iterator is empty.
+    pub fn annotations_text(&self) -> impl Iterator<Item = Option<&str>> {
+        self.annotations()
+            .iter()
+            .map(|annotation| annotation.text())
     }
 
-    fn read_string(&mut self) -> IonResult<Option<String>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::String(ref value)) => Ok(Some(value.clone())),
-            _ => Ok(None),
-        }
+    /// If the reader is positioned over a struct field whose name was written as a symbol ID
+    /// (e.g. `$10` in `{$10: 1}`), returns that symbol ID. Returns `None` if the field name was
+    /// written as text (`foo` or `'foo'`) or if the reader is not positioned over a struct field.
+    pub fn field_symbol_id(&self) -> Option<SymbolId> {
+        self.current_field_name.as_ref()?.local_sid()
     }
 
-    fn string_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
-    where
-        F: FnOnce(&str) -> U,
-    {
-        // TODO: This function format is a holdover from pre-NLL Rust.
-        //       https://github.com/amzn/ion-rust/issues/335
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::String(ref value)) => Ok(Some(f(value.as_str()))),
-            _ => Ok(None),
+    /// After `next()` has returned a decoding error at the top level, discards the malformed text
+    /// so that reading can resume with the values that follow it. This is a best-effort recovery
+    /// intended for streams that put each top-level value on its own line: the reader discards
+    /// the rest of the line on which the malformed text begins, so the next call to `next()` will
+    /// begin with the value at the start of the following line. Any other values on the
+    /// malformed line are skipped too.
+    ///
+    /// If the reader is not at the top level or the most recent call to `next()` did not fail,
+    /// returns an illegal operation error.
+    pub fn resync_to_next_top_level(&mut self) -> IonResult<()> {
+        if !self.parents.is_empty() {
+            return illegal_operation("Cannot resync the reader: it is not at the top level.");
         }
-    }
-
-    fn string_bytes_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
-    where
-        F: FnOnce(&[u8]) -> U,
-    {
-        // TODO: This function format is a holdover from pre-NLL Rust.
-        //       https://github.com/amzn/ion-rust/issues/335
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::String(ref value)) => Ok(Some(f(value.as_bytes()))),
-            _ => Ok(None),
+        if !self.last_parse_failed {
+            return illegal_operation(
+                "Cannot resync the reader: the most recent call to next() did not fail.",
+            );
         }
-    }
-
-    fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Symbol(ref value)) => Ok(Some(value.clone())),
-            _ => Ok(None),
+        self.last_parse_failed = false;
+        self.current_ivm = None;
+        self.current_value = None;
+        self.current_field_name = None;
+        self.deferred_value = None;
+        // Skip any whitespace that precedes the malformed text; it may begin on a later line.
+        loop {
+            let text = self.buffer.remaining_text();
+            let leading_whitespace = text.len() - text.trim_start().len();
+            let is_blank = leading_whitespace == text.len();
+            self.consume(leading_whitespace)?;
+            if !is_blank {
+                break;
+            }
+            if self.load_next_line()? == 0 {
+                // We've reached the end of the stream; the next call to `next()` returns None.
+                return Ok(());
+            }
         }
-    }
-
-    fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Blob(ref value)) => Ok(Some(value.clone())),
-            _ => Ok(None),
+        // Discard the rest of the line on which the malformed text begins.
+        loop {
+            let text = self.buffer.remaining_text();
+            if let Some(newline) = text.find('\n') {
+                return self.consume(newline + 1);
+            }
+            let length = text.len();
+            self.consume(length)?;
+            if self.load_next_line()? == 0 {
+                return Ok(());
+            }
         }
     }
 
-    fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
-    where
-        F: FnOnce(&[u8]) -> U,
-    {
-        // TODO: This function format is a holdover from pre-NLL Rust.
-        //       https://github.com/amzn/ion-rust/issues/335
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Blob(ref value)) => Ok(Some(f(value.as_slice()))),
-            _ => Ok(None),
-        }
+    /// Returns the 1-based number of the line on which the next unparsed token in the stream begins.
+    /// This is used to report the location of parsing errors.
+    fn current_line(&self) -> usize {
+        let remaining_text = self.buffer.remaining_text();
+        let leading_whitespace =
+            &remaining_text[..remaining_text.len() - remaining_text.trim_start().len()];
+        self.buffer.lines_consumed() + leading_whitespace.matches('\n').count() + 1
     }
 
-    fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Clob(ref value)) => Ok(Some(value.clone())),
-            _ => Ok(None),
+    /// Returns the byte range in the input stream of each of the current value's annotations,
+    /// in order. Each range covers only the annotation's symbol (e.g. `foo` or `'foo'`), not the
+    /// `::` delimiter or any surrounding whitespace and comments. If the reader is not positioned
+    /// over a value or the value has no annotations, returns an empty `Vec`.
+    pub fn annotation_spans(&self) -> Vec<Range<usize>> {
+        let number_of_annotations = match self.current_value.as_ref() {
+            Some(current) => current.annotations().len(),
+            None => return Vec::new(),
+        };
+        let text = self.buffer.text(self.current_value_range.clone());
+        let start = self.current_value_offset().unwrap();
+        let mut spans = Vec::with_capacity(number_of_annotations);
+        let mut remaining = text;
+        while spans.len() < number_of_annotations {
+            let symbol_text = match whitespace_or_comments(remaining) {
+                Ok((symbol_text, _)) => symbol_text,
+                Err(_) => break,
+            };
+            // Find the end of the symbol itself, then skip past the `::` that follows it.
+            let (after_symbol, after_annotation) =
+                match (parse_symbol(symbol_text), parse_annotation(symbol_text)) {
+                    (Ok((after_symbol, _)), Ok((after_annotation, _))) => {
+                        (after_symbol, after_annotation)
+                    }
+                    _ => break,
+                };
+            let symbol_start = start + text.len() - symbol_text.len();
+            let symbol_end = start + text.len() - after_symbol.len();
+            spans.push(symbol_start..symbol_end);
+            remaining = after_annotation;
         }
+        spans
     }
 
-    fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
-    where
-        F: FnOnce(&[u8]) -> U,
-    {
-        // TODO: This function format is a holdover from pre-NLL Rust.
-        //       https://github.com/amzn/ion-rust/issues/335
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Clob(ref value)) => Ok(Some(f(value.as_slice()))),
-            _ => Ok(None),
-        }
+    /// Returns the offset in the input stream at which the current value's text begins. This
+    /// includes any leading whitespace, comments, and annotations. If the reader is not positioned
+    /// over a value, returns None.
+    pub(crate) fn current_value_offset(&self) -> Option<usize> {
+        self.current_value.as_ref()?;
+        Some(self.bytes_read - self.buffer.offset() + self.current_value_range.start)
     }
 
-    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
-        match self.current_value.as_ref().map(|current| current.value()) {
-            Some(TextValue::Timestamp(ref value)) => Ok(Some(value.clone())),
-            _ => Ok(None),
+    /// Returns the source text of the current value without any leading whitespace, comments, or
+    /// annotations. The text may be followed by a delimiter or other trailing characters.
+    fn current_value_text(&self) -> Option<&str> {
+        self.current_value.as_ref()?;
+        let text = self.buffer.text(self.current_value_range.clone());
+        match preceded(whitespace_or_comments, opt(parse_annotations))(text) {
+            Ok((value_text, _annotations)) => Some(value_text),
+            Err(_) => Some(text),
         }
     }
 
-    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>> {
-        // TODO: This is deprecated. Remove it from the trait.
-        unimplemented!("`read_datetime` is being removed; use `read_timestamp` instead")
-    }
-
-    fn step_in(&mut self) -> IonResult<()> {
+    // Pushes the container over which the reader is positioned onto the `parents` stack.
+    fn step_into_current_container(&mut self) -> IonResult<()> {
         match &self.current_value {
+            Some(value)
+                if matches!(value.value(), TextValue::Null(_))
+                    && value.ion_type().is_container() =>
+            {
+                illegal_operation(format!("Cannot step_in() to a null {:?}", value.ion_type()))
+            }
             Some(value) if value.ion_type().is_container() => {
                 self.parents
                     .push(ParentContainer::new(value.value().ion_type()));
@@ -585,426 +1577,3575 @@ impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
         }
     }
 
-    fn step_out(&mut self) -> IonResult<()> {
-        if self.parents.is_empty() {
-            return illegal_operation(
-                "Cannot call `step_out()` when the reader is at the top level.".to_string(),
-            );
-        }
-
-        // The container we're stepping out of.
-        let parent = self.parents.last().unwrap();
+    // If the reader is positioned over the beginning of a container, moves past the rest of the
+    // container by stepping into it, moving past its contents, and stepping back out. Typed null
+    // containers (e.g. `null.list`) have no contents and do not need to be skipped.
+    fn skip_current_container(&mut self) -> IonResult<()> {
+        let need_to_skip_container = self
+            .current_value
+            .as_ref()
+            .map(|v| {
+                v.value().ion_type().is_container() && !matches!(v.value(), TextValue::Null(_))
+            })
+            .unwrap_or(false);
 
-        // If we're not at the end of the current container, advance the cursor until we are.
-        // Unlike the binary reader, which can skip-scan, the text reader must visit every value
-        // between its current position and the end of the container.
-        if !parent.is_exhausted() {
-            while let Some(_ignored_value) = self.next()? {}
+        if need_to_skip_container {
+            // Skipping a container does not count as entering it.
+            self.step_into_current_container()?;
+            // Either way, this leaves only the closing delimiter for `step_out()` to find.
+            if self.validate_skipped_containers {
+                self.parse_container_contents()?;
+            } else {
+                self.skip_container_contents()?;
+            }
+            self.step_out()?;
         }
+        Ok(())
+    }
 
-        // Remove the parent container from the stack and clear the current value.
-        let _ = self.parents.pop();
+    fn load_next_value(&mut self) -> IonResult<()> {
+        // If the reader's current value is the beginning of a container and the user calls `next()`,
+        // we need to skip the entire container.
+        self.skip_current_container()?;
+
+        // Unset variables holding onto information about the previous position.
+        self.current_ivm = None;
         self.current_value = None;
+        self.current_field_name = None;
 
         if self.parents.is_empty() {
-            // We're at the top level; nothing left to do.
+            // The `parents` stack is empty. We're at the top level.
+
+            // If the reader has already found EOF (the end of the top level), there's no need to
+            // try to read more data. Return Ok(None).
+            if self.is_eof {
+                self.current_value = None;
+                return Ok(());
+            }
+
+            // Otherwise, see if the next token in the stream is an Ion Version Marker.
+            if let Ok(Some(version)) = self.parse_next(ion_version_marker) {
+                // We found an IVM; we currently only support Ion 1.0.
+                if version != (1, 0) {
+                    return decoding_error(format!(
+                        "Encountered a version marker for v{}.{}, but only v1.0 is supported.",
+                        version.0, version.1
+                    ));
+                }
+                self.current_ivm = Some((1, 0));
+                self.ion_version = (1, 0);
+                return Ok(());
+            }
+
+            // If it wasn't an IVM, it has to be a value.
+            let value = self.next_top_level_value();
+            match value {
+                Ok(None) => {
+                    // We hit EOF; make a note of it and clear the current value.
+                    self.is_eof = true;
+                    self.current_value = None;
+                }
+                Ok(Some(ref value))
+                    if self.forbid_top_level_symbols
+                        && matches!(value.value(), TextValue::Symbol(_)) =>
+                {
+                    return decoding_error(format!(
+                        "Found a symbol ({:?}) at the top level near line {}; if this was meant \
+                        to be a string, wrap it in double quotes.",
+                        value.value(),
+                        self.current_line()
+                    ));
+                }
+                Ok(Some(value)) => {
+                    // We read a value successfully; set it as our current value.
+                    self.current_value = Some(self.normalize_symbol(value));
+                    self.current_value_range = self.last_parsed_range.clone();
+                    self.check_integer_range()?;
+                }
+                Err(e) => return Err(e),
+            };
             return Ok(());
         }
+        // Otherwise, the `parents` stack is not empty. We're inside a container.
 
-        // We've stepped out, but the reader isn't at the top level. We're still inside another
-        // container. Make sure the container was followed by either the appropriate delimiter
-        // or the end of its parent.
-        let container_type = self.parents.last().unwrap().ion_type();
-        match container_type {
-            IonType::List => {
-                let _ = self.parse_expected("list delimiter or end", list_delimiter)?;
+        // We only need to read the parent's fields here, so we borrow it rather than copying it.
+        // The borrow ends once we've looked up its IonType below.
+        let parent = self.parents.last().unwrap();
+        // If the reader had already found the end of this container, return Ok(None).
+        if parent.is_exhausted() {
+            self.current_value = None;
+            return Ok(());
+        }
+        // Otherwise, try to read the next value. The syntax we expect will depend on the
+        // IonType of the parent container.
+        let value = match parent.ion_type() {
+            IonType::List => self.next_list_value(),
+            IonType::SExpression => self.next_s_expression_value(),
+            IonType::Struct => {
+                // If the reader finds a field name...
+                if let Some(field_name) = self.next_struct_field_name()? {
+                    // ...remember it and return the field value that follows.
+                    self.current_field_name = Some(field_name);
+                    let field_value_result = self.next_struct_field_value()?;
+                    Ok(Some(field_value_result))
+                } else {
+                    // Otherwise, this is the end of the struct.
+                    Ok(None)
+                }
             }
-            IonType::SExpression => {
-                let _ =
-                    self.parse_expected("s-expression delimiter or end", s_expression_delimiter)?;
+            other => unreachable!(
+                "The reader's `parents` stack contained a scalar value: {:?}",
+                other
+            ),
+        };
+
+        match value {
+            Ok(None) => {
+                // If the parser returns Ok(None), we've just encountered the end of the container for
+                // the first time. Set `is_exhausted` so we won't try to parse more until `step_out()` is
+                // called.
+                // Our earlier borrow of the parent was read-only. To modify it, we'll need to get a
+                // mutable reference.
+                self.parents.last_mut().unwrap().set_exhausted(true);
+                self.current_value = None;
             }
-            IonType::Struct => {
-                let _ = self.parse_expected("struct delimiter or end", struct_delimiter)?;
+            Ok(Some(value)) => {
+                // We successfully read a value. Set it as the current value.
+                self.current_value = Some(self.normalize_symbol(value));
+                self.current_value_range = self.last_parsed_range.clone();
+                self.check_integer_range()?;
             }
-            scalar => unreachable!("Stepping out of a scalar type: {:?}", scalar),
+            Err(e) => return Err(e),
         };
+
         Ok(())
     }
 
-    fn depth(&self) -> usize {
-        self.parents.len()
+    // If the current value is an integer that does not fit in an i64 and the reader was not
+    // configured to clamp such integers, clears the current value and returns an error.
+    fn check_integer_range(&mut self) -> IonResult<()> {
+        if self.integer_overflow_mode == IntegerOverflowMode::Clamp {
+            return Ok(());
+        }
+        if let Some(text) = self.out_of_range_integer_text() {
+            let result = self.integer_overflow_error(text);
+            self.current_value = None;
+            return result;
+        }
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod reader_tests {
-    use rstest::*;
+    // If the current value is an integer that was clamped to an i64 bound because it does not fit
+    // in an i64, returns its text.
+    fn out_of_range_integer_text(&self) -> Option<&str> {
+        match self.current_value.as_ref()?.value() {
+            TextValue::Integer(value) if *value == i64::MIN || *value == i64::MAX => {}
+            _ => return None,
+        }
+        let text = self.current_value_text()?;
+        // Integers are made up of a sign, digits, underscores, and a radix prefix (e.g. `0x`).
+        let unsigned_text = text.strip_prefix('-').unwrap_or(text);
+        let length = unsigned_text
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(unsigned_text.len());
+        let text = &text[..length + text.len() - unsigned_text.len()];
+        if is_out_of_range_integer(text) {
+            return Some(text);
+        }
+        None
+    }
 
-    use crate::raw_reader::RawStreamItem;
-    use crate::raw_symbol_token::{local_sid_token, text_token};
-    use crate::result::IonResult;
-    use crate::text::raw_text_reader::RawTextReader;
-    use crate::text::text_value::{IntoAnnotations, TextValue};
-    use crate::types::decimal::Decimal;
-    use crate::types::timestamp::Timestamp;
-    use crate::{IonType, RawReader};
+    // If the current value is an integer that does not fit in an i64, returns its exact value.
+    fn out_of_range_integer(&self) -> Option<BigInt> {
+        self.out_of_range_integer_text().and_then(parse_big_integer)
+    }
 
-    fn next_type(reader: &mut RawTextReader<&str>, ion_type: IonType, is_null: bool) {
-        assert_eq!(
-            reader.next().unwrap().unwrap(),
-            RawStreamItem::Value(ion_type, is_null)
-        );
+    // If the reader was configured to report symbols as strings, converts a symbol value with
+    // known text (or a `null.symbol`) into the equivalent string. Other values are returned as-is.
+    fn normalize_symbol(&self, value: AnnotatedTextValue) -> AnnotatedTextValue {
+        if !self.symbols_as_strings {
+            return value;
+        }
+        match value.into_parts() {
+            (annotations, TextValue::Symbol(RawSymbolToken::Text(text))) => {
+                AnnotatedTextValue::new(annotations, TextValue::String(text))
+            }
+            (annotations, TextValue::Null(IonType::Symbol)) => {
+                AnnotatedTextValue::new(annotations, TextValue::Null(IonType::String))
+            }
+            (annotations, value) => AnnotatedTextValue::new(annotations, value),
+        }
     }
 
-    fn annotations_eq<I: IntoAnnotations>(reader: &mut RawTextReader<&str>, expected: I) {
-        let annotations = expected.into_annotations();
-        assert_eq!(reader.annotations(), annotations.as_slice());
+    // Parses each of the values in the container that the reader has most recently stepped into,
+    // including the values in any nested containers, stopping at the container's closing
+    // delimiter. Nested containers are entered and exited in a loop rather than by recursing, so
+    // the stack space used does not depend on how deeply they are nested.
+    fn parse_container_contents(&mut self) -> IonResult<()> {
+        let depth = self.parents.len();
+        loop {
+            self.load_next_value()?;
+            match self.current_value.as_ref() {
+                Some(value)
+                    if value.ion_type().is_container()
+                        && !matches!(value.value(), TextValue::Null(_)) =>
+                {
+                    self.step_into_current_container()?
+                }
+                Some(_) => {}
+                // The nested container is exhausted, so `step_out()` will not call `next()`.
+                None if self.parents.len() > depth => self.step_out()?,
+                None => return Ok(()),
+            }
+        }
     }
 
-    #[test]
-    fn test_skipping_containers() -> IonResult<()> {
-        let ion_data = r#"
-            0 [1, 2, 3] (4 5) 6
-        "#;
-        let reader = &mut RawTextReader::new(ion_data);
-        next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 0);
+    // Consumes the text in the buffer up to (but not including) the closing delimiter of the
+    // container that the reader has most recently stepped into.
+    fn skip_container_contents(&mut self) -> IonResult<()> {
+        let position = self.find_container_end()?;
+        self.consume(position)
+    }
 
-        next_type(reader, IonType::List, false);
-        reader.step_in()?;
-        next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 1);
-        reader.step_out()?;
-        // This should have skipped over the `2, 3` at the end of the list.
-        next_type(reader, IonType::SExpression, false);
-        // Don't step into the s-expression. Instead, skip over it.
-        next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 6);
-        Ok(())
+    // Returns the offset (relative to the buffer's remaining text) of the closing delimiter of the
+    // container that the reader has most recently stepped into, loading more text as needed.
+    // Rather than parsing the values inside the container, this scans for the matching delimiter,
+    // counting the opening and closing delimiters of any nested containers. Delimiters that appear
+    // inside strings, quoted symbols, lobs, and comments are ignored. The amount of work done does
+    // not depend on how deeply nested the skipped containers are.
+    fn find_container_end(&mut self) -> IonResult<usize> {
+        // The container being skipped is already open.
+        let mut scanner = DelimiterScanner::new(1);
+        let mut position = 0;
+        let mut is_exhausted = false;
+        loop {
+            let text = self.buffer.remaining_text().as_bytes();
+            // Some tokens (e.g. `'''`, `//`) are recognized by looking at the bytes that follow
+            // them. Make sure those have been loaded before we examine the byte at `position`.
+            // Closing delimiters don't need any, so we never read past the end of the container.
+            let lookahead = text
+                .get(position)
+                .map(|byte| scanner.lookahead(*byte))
+                .unwrap_or(0);
+            if position + lookahead >= text.len() && !is_exhausted {
+                is_exhausted = self.load_next_line()? == 0;
+                continue;
+            }
+            if position >= text.len() {
+                return decoding_error(format!(
+                    "Unexpected end of stream while skipping a container near line {}.",
+                    self.current_line()
+                ));
+            }
+            let width = scanner.scan_token(&text[position..]);
+            if scanner.depth() == 0 {
+                break;
+            }
+            position += width;
+        }
+        Ok(position)
+    }
+
+    /// Assumes that the reader is at the top level and attempts to parse the next value or IVM in
+    /// the stream.
+    fn next_top_level_value(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
+        match self.parse_next(top_level_value) {
+            Ok(Some(value)) => {
+                // A value that is immediately followed by `::` was meant to be an annotation, but
+                // only symbols can be used as annotations. (A symbol followed by `::` would have
+                // been parsed as an annotation if a value had followed it.)
+                if !matches!(value.value(), TextValue::Symbol(_))
+                    && starts_with_annotation_delimiter(self.buffer.remaining_text())
+                {
+                    let text = self.buffer.text(self.last_parsed_range.clone()).trim();
+                    return self.non_symbol_annotation_error(text);
+                }
+                Ok(Some(value))
+            }
+            Ok(None) => {
+                // The top level is the only depth at which EOF is legal. If we encounter an EOF,
+                // double check that the buffer doesn't actually have a value in it. See the
+                // comments in [parse_value_at_eof] for a detailed explanation of this.
+                self.parse_value_at_eof()
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Assumes that the reader is inside a list and attempts to parse the next value.
+    /// If the next token in the stream is an end-of-list delimiter (`]`), returns Ok(None).
+    fn next_list_value(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
+        let follows_comma = self.last_parse_ended_with_comma;
+        let value = self.parse_expected("a list", list_value_or_end)?;
+        if value.is_none() && follows_comma {
+            self.trailing_comma_check("list")?;
+        }
+        Ok(value)
+    }
+
+    /// Assumes that the reader is inside an s-expression and attempts to parse the next value.
+    /// If the next token in the stream is an end-of-s-expression delimiter (`)`), returns Ok(None).
+    fn next_s_expression_value(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
+        self.parse_expected("an s-expression", s_expression_value_or_end)
+    }
+
+    /// Assumes that the reader is inside an struct and attempts to parse the next field name.
+    /// If the next token in the stream is an end-of-struct delimiter (`}`), returns Ok(None).
+    fn next_struct_field_name(&mut self) -> IonResult<Option<RawSymbolToken>> {
+        // If there isn't another value, this returns Ok(None).
+        let follows_comma = self.last_parse_ended_with_comma;
+        let field_name = self.parse_expected("a struct field name", struct_field_name_or_end)?;
+        if field_name.is_none() && follows_comma {
+            self.trailing_comma_check("struct")?;
+        }
+        Ok(field_name)
+    }
+
+    // Called when the end of a list or struct immediately follows a comma. Returns an error if
+    // the reader was configured to forbid trailing commas.
+    fn trailing_comma_check(&self, container_name: &str) -> IonResult<()> {
+        if self.allow_trailing_commas {
+            return Ok(());
+        }
+        decoding_error(format!(
+            "Found a trailing comma at the end of a {} near line {}.",
+            container_name,
+            self.current_line()
+        ))
+    }
+
+    /// Assumes that the reader is inside a struct AND that a field has already been successfully
+    /// parsed from input using [next_struct_field_name] and attempts to parse the next value.
+    /// In this input position, only a value (or whitespace/comments) are legal. Anything else
+    /// (including EOF) will result in a decoding error.
+    fn next_struct_field_value(&mut self) -> IonResult<AnnotatedTextValue> {
+        // Only called after a call to [next_struct_field_name] that returns Some(field_name).
+        // It is not legal for a field name to be followed by a '}' or EOF.
+        // If there isn't another value, returns an Err.
+        self.parse_expected("a struct field value", struct_field_value)
+    }
+
+    /// Attempts to parse the next entity from the stream using the provided parser.
+    /// Returns a decoding error if EOF is encountered while parsing.
+    /// If the parser encounters an error, it will be returned as-is.
+    fn parse_expected<P, O>(&mut self, entity_name: &str, parser: P) -> IonResult<O>
+    where
+        P: Fn(&str) -> IResult<&str, O>,
+    {
+        match self.parse_next(parser) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => decoding_error(format!(
+                "Unexpected end of input while reading {} on line {}: '{}'",
+                entity_name,
+                self.current_line(),
+                self.buffer.remaining_text()
+            )),
+            Err(e) => decoding_error(format!(
+                "Parsing error occurred while parsing {} near line {}:\n'{}'\n{}",
+                entity_name,
+                self.current_line(),
+                self.buffer.remaining_text(),
+                e
+            )),
+        }
+    }
+
+    /// Attempts to parse the next entity from the stream using the provided parser.
+    /// If there isn't enough data in the buffer for the parser to match its input conclusively,
+    /// more data will be loaded into the buffer and the parser will be called again.
+    /// If EOF is encountered, returns `Ok(None)`.
+    fn parse_next<P, O>(&mut self, parser: P) -> IonResult<Option<O>>
+    where
+        P: Fn(&str) -> IResult<&str, O>,
+    {
+        if self.is_eof {
+            return Ok(None);
+        }
+
+        let value = 'parse: loop {
+            // Note the number of bytes currently in the text buffer
+            let length_before_parse = self.buffer.remaining_text().len();
+            let input_text = self.buffer.remaining_text();
+            // Invoke the top_level_value() parser; this will attempt to recognize the next value
+            // in the stream and return a &str slice containing the remaining, not-yet-parsed text.
+            match parser(input_text) {
+                // If `top_level_value` returns 'Incomplete', there wasn't enough text in the buffer
+                // to match the next value. No syntax errors have been encountered (yet?), but we
+                // need to load more text into the buffer before we try to parse it again.
+                Err(Incomplete(_needed)) => {
+                    // Ask the buffer to load another line of text.
+                    // TODO: Currently this loads a single line at a time for easier testing.
+                    //       We may wish to bump it to a higher number of lines at a time (8?)
+                    //       for efficiency once we're confident in the correctness.
+                    if self.load_next_line()? == 0 {
+                        // If load_next_line() returns Ok(0), we've reached the end of our input.
+                        self.is_eof = true;
+                        // The buffer had an `Incomplete` value in it; now that we know we're at EOF,
+                        // we can determine whether the buffer's contents should actually be
+                        // considered complete.
+                        return Ok(None);
+                    }
+                    continue;
+                }
+                Ok((remaining_text, value)) => {
+                    // Our parser successfully matched a value.
+                    // Note the length of the text that remains after parsing.
+                    let length_after_parse = remaining_text.len();
+                    // The difference in length tells us how many bytes were part of the
+                    // text representation of the value that we found.
+                    let bytes_consumed = length_before_parse - length_after_parse;
+                    self.last_parse_ended_with_comma = input_text[..bytes_consumed].ends_with(',');
+                    // Discard `bytes_consumed` bytes from the TextBuffer.
+                    self.consume(bytes_consumed)?;
+                    let end = self.buffer.offset();
+                    self.last_parsed_range = (end - bytes_consumed)..end;
+                    // Break out of the read/parse loop, returning the value that we matched.
+                    break 'parse value;
+                }
+                Err(e) => {
+                    if let Some(text) = non_symbol_annotation_text(self.buffer.remaining_text()) {
+                        return self.non_symbol_annotation_error(text);
+                    }
+                    if let Some(text) = malformed_numeric_text(self.buffer.remaining_text()) {
+                        return self.malformed_numeric_value_error(text);
+                    }
+                    // Return an error that contains the text currently in the buffer (i.e. what we
+                    // were attempting to parse with `top_level_value`.)
+                    // TODO: We probably don't want to surface the nom error (`e`) directly, but it's
+                    //       useful for debugging.
+                    return decoding_error(format!(
+                        "Parsing error occurred near line {}: '{}': '{}'",
+                        self.current_line(),
+                        self.buffer.remaining_text(),
+                        e
+                    ));
+                }
+            };
+        };
+
+        Ok(Some(value))
+    }
+
+    // Loads the next line of text into the buffer, enforcing `max_total_bytes` if it was set.
+    // If the input is exhausted, returns Ok(0).
+    fn load_next_line(&mut self) -> IonResult<usize> {
+        let max_total_bytes = match self.max_total_bytes {
+            Some(max_total_bytes) => max_total_bytes,
+            None => return Ok(self.buffer.load_next_line()?),
+        };
+        match self.buffer.load_next_line_within(max_total_bytes)? {
+            Some(bytes_loaded) => Ok(bytes_loaded),
+            None => decoding_error(format!(
+                "The input exceeded the reader's limit of {} bytes near line {}.",
+                max_total_bytes,
+                self.buffer.lines_loaded() + 1
+            )),
+        }
+    }
+
+    // Constructs the error returned when a parser fails on a token that begins like a number.
+    // Timestamps are reported separately from other numeric values, and timestamps with a time of
+    // day but no offset are called out specifically.
+    fn malformed_numeric_value_error<V>(&self, text: &str) -> IonResult<V> {
+        if is_missing_timezone_offset(text) {
+            return decoding_error(format!(
+                "Found a timestamp with no offset near line {}: '{}'; timestamps that include a \
+                time of day must end with an offset like `Z` or `-05:00`.",
+                self.current_line(),
+                text
+            ));
+        }
+        if looks_like_timestamp(text) {
+            return decoding_error(format!(
+                "Found a malformed timestamp near line {}: '{}'",
+                self.current_line(),
+                text
+            ));
+        }
+        if is_out_of_range_integer(text) {
+            return self.integer_overflow_error(text);
+        }
+        decoding_error(format!(
+            "Found a malformed numeric value near line {}: '{}'",
+            self.current_line(),
+            text
+        ))
+    }
+
+    // Constructs the error returned when an integer does not fit in an i64.
+    fn integer_overflow_error<V>(&self, text: &str) -> IonResult<V> {
+        decoding_error(format!(
+            "Found an integer near line {} that overflows an i64: '{}'; integers must be in \
+            the range {}..={}.",
+            self.current_line(),
+            text,
+            i64::MIN,
+            i64::MAX
+        ))
+    }
+
+    // Constructs the error returned when a value other than a symbol is used as an annotation.
+    fn non_symbol_annotation_error<V>(&self, text: &str) -> IonResult<V> {
+        decoding_error(format!(
+            "Found a non-symbol annotation near line {}: '{}'; annotations must be symbols. \
+            (Keywords like `null` must be quoted to be used as annotations.)",
+            self.current_line(),
+            text
+        ))
+    }
+
+    // Discards `number_of_bytes` bytes from the text buffer, writing them to the tee (if any).
+    fn consume(&mut self, number_of_bytes: usize) -> IonResult<()> {
+        if let Some(tee) = self.tee.as_mut() {
+            tee.write_all(&self.buffer.remaining_text().as_bytes()[..number_of_bytes])?;
+        }
+        self.buffer.consume(number_of_bytes);
+        self.bytes_read += number_of_bytes;
+        Ok(())
+    }
+
+    // Parses the contents of the text buffer again with the knowledge that we're at the end of the
+    // input stream. This allows us to resolve a number of ambiguous cases.
+    // For a detailed description of the problem that this addresses, please see:
+    // https://github.com/amzn/ion-rust/issues/318
+    // This method should only be called when the reader is at the top level. An EOF at any other
+    // depth is an error.
+    fn parse_value_at_eof(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
+        // An arbitrary, cheap-to-parse Ion value that we append to the buffer when its contents at
+        // EOF are ambiguous.
+        const SENTINEL_ION_TEXT: &str = "\n0\n";
+        // Make a note of the buffer's length; we're about to modify it.
+        let original_length = self.buffer.remaining_text().len();
+        let start = self.buffer.offset();
+        self.last_parsed_range = start..(start + original_length);
+        if self.buffer.is_blank() {
+            // Only whitespace and comments remain, so there's no value to disambiguate.
+            if let Some(tee) = self.tee.as_mut() {
+                tee.write_all(self.buffer.remaining_text().as_bytes())?;
+            }
+            return Ok(None);
+        }
+        // Append our sentinel value to the end of the input buffer.
+        self.buffer.inner().push_str(SENTINEL_ION_TEXT);
+        // If the buffer contained a value, the newline will indicate that the contents of the
+        // buffer were complete. For example:
+        // * the integer `7` becomes `7\n`; it wasn't the first digit in a truncated `755`.
+        // * the boolean `true` becomes `false\n`; it wasn't actually half of the
+        //   identifier `falseTeeth`.
+        //
+        // If the buffer contained a value that's written in segments, the extra `0` will indicate
+        // that no more segments are coming. For example:
+        // * `foo::bar` becomes `foo::bar\n0\n`; the parser can see that 'bar' is a value, not
+        //    another annotation in the sequence.
+        // * `'''long-form string'''` becomes `'''long-form string'''\n0\n`; the parser can see that
+        //   there aren't any more long-form string segments in the sequence.
+        //
+        // Attempt to parse the updated buffer.
+        let value = match top_level_value(self.buffer.remaining_text()) {
+            Ok(("\n", value))
+                if value.annotations().is_empty() && *value.value() == TextValue::Integer(0) =>
+            {
+                // We found the unannotated zero that we appended to the end of the buffer.
+                // The "\n" in this pattern is the unparsed text left in the buffer,
+                // which indicates that our 0 was parsed.
+                Ok(None)
+            }
+            Ok((remaining_text, value))
+                if !matches!(value.value(), TextValue::Symbol(_))
+                    && starts_with_annotation_delimiter(remaining_text) =>
+            {
+                let value_length = self.buffer.remaining_text().len() - remaining_text.len();
+                let text = self.buffer.remaining_text()[..value_length].trim();
+                self.non_symbol_annotation_error(text)
+            }
+            Ok((_remaining_text, value)) => {
+                // We found something else. The zero is still in the buffer; we can leave it there.
+                // The reader's `is_eof` flag has been set, so the text buffer will never be used
+                // again. Return the value we found.
+                Ok(Some(value))
+            }
+            Err(Incomplete(_needed)) => {
+                decoding_error(format!(
+                    "Unexpected end of input on line {}: '{}'",
+                    self.current_line(),
+                    &self.buffer.remaining_text()[..original_length] // Don't show the extra `\n0\n`
+                ))
+            }
+            Err(e) => {
+                // Don't show the extra `\n0\n`
+                let text = &self.buffer.remaining_text()[..original_length];
+                if let Some(annotation_text) = non_symbol_annotation_text(text) {
+                    self.non_symbol_annotation_error(annotation_text)
+                } else if let Some(numeric_text) = malformed_numeric_text(text) {
+                    self.malformed_numeric_value_error(numeric_text)
+                } else {
+                    decoding_error(format!(
+                        "Parsing error occurred near line {}: '{}': '{}'",
+                        self.current_line(),
+                        text,
+                        e
+                    ))
+                }
+            }
+        };
+
+        // The buffer's remaining text is never consumed once the reader has reached EOF, so it is
+        // written to the tee here instead. This method is only called once per stream.
+        if let (Ok(_), Some(tee)) = (&value, self.tee.as_mut()) {
+            tee.write_all(&self.buffer.remaining_text().as_bytes()[..original_length])?;
+        }
+
+        // If we didn't consume the sentinel value, remove the sentinel value from the buffer.
+        // Doing so makes this method idempotent.
+        if self.buffer.remaining_text().ends_with(SENTINEL_ION_TEXT) {
+            let length = self.buffer.remaining_text().len();
+            self.buffer
+                .inner()
+                .truncate(length - SENTINEL_ION_TEXT.len());
+        }
+
+        value
+    }
+}
+
+// When a parser fails, this checks whether the next token in `text` (ignoring any leading whitespace,
+// comments, or annotations) begins like a number. If so, it returns the text of that token so it
+// can be included in a more helpful error message.
+fn malformed_numeric_text(text: &str) -> Option<&str> {
+    let text = match preceded(whitespace_or_comments, opt(parse_annotations))(text) {
+        Ok((remaining_text, _annotations)) => remaining_text,
+        Err(_) => text.trim_start(),
+    };
+    let token_length = text
+        .find(|c: char| c.is_whitespace() || "{}[](),\"'".contains(c))
+        .unwrap_or(text.len());
+    let token = &text[..token_length];
+    let unsigned_token = token.strip_prefix('-').unwrap_or(token);
+    if unsigned_token.starts_with(|c: char| c.is_ascii_digit()) {
+        return Some(token);
+    }
+    None
+}
+
+// Returns true if `text` begins with a `::` delimiter, ignoring any leading whitespace or comments.
+fn starts_with_annotation_delimiter(text: &str) -> bool {
+    preceded(
+        whitespace_or_comments,
+        tag::<_, _, nom::error::Error<&str>>("::"),
+    )(text)
+    .is_ok()
+}
+
+// When a parser fails, this checks whether the next token in `text` (ignoring any leading
+// whitespace, comments, or annotations) is a value other than a symbol followed by `::`. If so, it
+// returns the text of that value so it can be included in a more helpful error message.
+fn non_symbol_annotation_text(text: &str) -> Option<&str> {
+    let text = match preceded(whitespace_or_comments, opt(parse_annotations))(text) {
+        Ok((remaining_text, _annotations)) => remaining_text,
+        Err(_) => text.trim_start(),
+    };
+    // Values with an explicit end like strings (`"foo"::5`) can be recognized directly.
+    if let Ok((remaining_text, value)) = scalar(text) {
+        if !matches!(value, TextValue::Symbol(_))
+            && starts_with_annotation_delimiter(remaining_text)
+        {
+            return Some(text[..text.len() - remaining_text.len()].trim_end());
+        }
+    }
+    // Other values (`5::6`, `null::5`) won't parse when they are followed by a `:`. Check whether
+    // the text leading up to the first `::` is a complete value on its own.
+    let token = text[..text.find("::")?].trim_end();
+    match scalar(&format!("{}\n", token)) {
+        Ok(("\n", TextValue::Symbol(_))) => None,
+        Ok(("\n", _value)) => Some(token),
+        _ => None,
+    }
+}
+
+// Converts a RawSymbolToken into the equivalent OwnedSymbolToken.
+fn owned_symbol_token(token: &RawSymbolToken) -> OwnedSymbolToken {
+    match token {
+        RawSymbolToken::Text(text) => owned::text_token(text.as_str()),
+        RawSymbolToken::SymbolId(sid) => owned::local_sid_token(*sid),
+    }
+}
+
+// Returns the text of a RawSymbolToken. Symbol IDs are rendered as `$ID`.
+fn symbol_token_text(token: &RawSymbolToken) -> String {
+    match token {
+        RawSymbolToken::Text(text) => text.clone(),
+        RawSymbolToken::SymbolId(sid) => format!("${}", sid),
+    }
+}
+
+// Uses a TextWriter to render a single top-level scalar, then removes the newline that the writer
+// emits after each top-level value.
+fn render<F>(write_scalar: F) -> IonResult<String>
+where
+    F: FnOnce(&mut TextWriter<&mut Vec<u8>>) -> IonResult<()>,
+{
+    let mut output = Vec::new();
+    let mut writer = TextWriter::new(&mut output);
+    write_scalar(&mut writer)?;
+    drop(writer);
+    let mut text = String::from_utf8(output)
+        .map_err(|e| decoding_error_raw(format!("Rendered text was not valid UTF-8: {}", e)))?;
+    text.truncate(text.trim_end().len());
+    Ok(text)
+}
+
+// Returned by the `annotations()` method below if there is no current value.
+const EMPTY_SLICE_RAW_SYMBOL_TOKEN: &[RawSymbolToken] = &[];
+
+// TODO: This implementation of the text reader eagerly materializes each value that it encounters
+//       in the stream and stores it in the reader as `current_value`. Each time a user requests
+//       a value via `read_i64`, `read_bool`, etc, a clone of `current_value` is returned (assuming
+//       its type is in alignment with the request).
+//       A better implementation would identify the input slice containing the next value without
+//       materializing it and then attempt to materialize it when the user calls `read_TYPE`. This
+//       would take less memory and would only materialize values that the user requests.
+//       See: https://github.com/amzn/ion-rust/issues/322
+impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
+    fn ion_version(&self) -> (u8, u8) {
+        // TODO: The text reader does not yet have IVM support
+        (1, 0)
+    }
+
+    fn next(&mut self) -> IonResult<Option<RawStreamItem>> {
+        let item = self.advance()?;
+        self.count_scalar(&item);
+        Ok(item)
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        self.current_value.as_ref().map(|v| v.ion_type())
+    }
+
+    fn is_null(&self) -> bool {
+        self.current_value
+            .as_ref()
+            .map(|v| matches!(v.value(), TextValue::Null(_)))
+            .unwrap_or(false)
+    }
+
+    fn annotations(&self) -> &[RawSymbolToken] {
+        self.current_value
+            .as_ref()
+            .map(|value| value.annotations())
+            .unwrap_or(EMPTY_SLICE_RAW_SYMBOL_TOKEN)
+    }
+
+    fn field_name(&self) -> Option<&RawSymbolToken> {
+        self.current_field_name.as_ref()
+    }
+
+    fn read_null(&mut self) -> IonResult<Option<IonType>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Null(ion_type)) => Ok(Some(*ion_type)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_bool(&mut self) -> IonResult<Option<bool>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Boolean(value)) => Ok(Some(*value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_i64(&mut self) -> IonResult<Option<i64>> {
+        if let Some(text) = self.out_of_range_integer_text() {
+            return self.integer_overflow_error(text);
+        }
+        self.read_i64_clamped()
+    }
+
+    fn read_f32(&mut self) -> IonResult<Option<f32>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Float(value)) => Ok(Some(*value as f32)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_f64(&mut self) -> IonResult<Option<f64>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Float(value)) => Ok(Some(*value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Decimal(ref value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
+        // TODO: This function is deprecated. Remove it from the trait.
+        unimplemented!("`read_datetime` is being removed; use `read_timestamp` instead")
+    }
+
+    fn read_string(&mut self) -> IonResult<Option<String>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::String(ref value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn string_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&str) -> U,
+    {
+        // TODO: This function format is a holdover from pre-NLL Rust.
+        //       https://github.com/amzn/ion-rust/issues/335
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::String(ref value)) => Ok(Some(f(value.as_str()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn string_bytes_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        // TODO: This function format is a holdover from pre-NLL Rust.
+        //       https://github.com/amzn/ion-rust/issues/335
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::String(ref value)) => Ok(Some(f(value.as_bytes()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Symbol(ref value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Blob(ref value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        // TODO: This function format is a holdover from pre-NLL Rust.
+        //       https://github.com/amzn/ion-rust/issues/335
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Blob(ref value)) => Ok(Some(f(value.as_slice()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        Ok(self.clob_bytes().map(<[u8]>::to_vec))
+    }
+
+    fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        // TODO: This function format is a holdover from pre-NLL Rust.
+        //       https://github.com/amzn/ion-rust/issues/335
+        Ok(self.clob_bytes().map(f))
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Timestamp(ref value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>> {
+        // TODO: This is deprecated. Remove it from the trait.
+        unimplemented!("`read_datetime` is being removed; use `read_timestamp` instead")
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        self.step_into_current_container()?;
+        self.stats.containers_entered += 1;
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if self.parents.is_empty() {
+            return illegal_operation(
+                "Cannot call `step_out()` when the reader is at the top level.".to_string(),
+            );
+        }
+
+        // The container we're stepping out of.
+        let parent = self.parents.last().unwrap();
+
+        // If we're not at the end of the current container, advance the cursor until we are.
+        // Unlike the binary reader, which can skip-scan, the text reader must visit every value
+        // between its current position and the end of the container.
+        if !parent.is_exhausted() {
+            while let Some(_ignored_value) = self.next()? {}
+        }
+
+        // Remove the parent container from the stack and clear the current value.
+        let _ = self.parents.pop();
+        self.current_value = None;
+
+        if self.parents.is_empty() {
+            // We're at the top level; nothing left to do.
+            return Ok(());
+        }
+
+        // We've stepped out, but the reader isn't at the top level. We're still inside another
+        // container. Make sure the container was followed by either the appropriate delimiter
+        // or the end of its parent.
+        let container_type = self.parents.last().unwrap().ion_type();
+        match container_type {
+            IonType::List => {
+                let _ = self.parse_expected("list delimiter or end", list_delimiter)?;
+            }
+            IonType::SExpression => {
+                let _ =
+                    self.parse_expected("s-expression delimiter or end", s_expression_delimiter)?;
+            }
+            IonType::Struct => {
+                let _ = self.parse_expected("struct delimiter or end", struct_delimiter)?;
+            }
+            scalar => unreachable!("Stepping out of a scalar type: {:?}", scalar),
+        };
+        Ok(())
+    }
+
+    fn depth(&self) -> usize {
+        self.parents.len()
+    }
+}
+
+#[cfg(test)]
+mod reader_tests {
+    use rstest::*;
+
+    use crate::raw_reader::RawStreamItem;
+    use crate::raw_symbol_token::{local_sid_token, text_token};
+    use crate::result::{decoding_error, IonError, IonResult};
+    use crate::system_reader::{equivalent_streams, SystemReader};
+    use crate::text::raw_text_reader::{
+        IntegerOverflowMode, Polarity, RawTextReader, RawTextReaderBuilder, ReaderStats, Sign,
+        ValueInfo,
+    };
+    use crate::text::text_value::{IntoAnnotations, TextValue};
+    use crate::types::coefficient::Sign as CoefficientSign;
+    use crate::types::decimal::Decimal;
+    use crate::types::timestamp::Timestamp;
+    use crate::value::owned::{self, OwnedElement, OwnedValue};
+    use crate::value::{AnyInt, Builder, Element};
+    use crate::{IonType, RawReader};
+    use num_bigint::{BigInt, BigUint, Sign as BigIntSign};
+    use std::collections::HashMap;
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
+
+    fn next_type(reader: &mut RawTextReader<&str>, ion_type: IonType, is_null: bool) {
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            RawStreamItem::Value(ion_type, is_null)
+        );
+    }
+
+    // Advances to the next item. If it's a non-null container, steps into it and advances to its
+    // first item instead, allowing test cases to wrap the value being tested in a container.
+    fn next_stepping_in(reader: &mut RawTextReader<&str>) -> IonResult<Option<RawStreamItem>> {
+        match reader.next()? {
+            Some(RawStreamItem::Value(ion_type, false)) if ion_type.is_container() => {
+                reader.step_in()?;
+                reader.next()
+            }
+            item => Ok(item),
+        }
+    }
+
+    fn annotations_eq<I: IntoAnnotations>(reader: &mut RawTextReader<&str>, expected: I) {
+        let annotations = expected.into_annotations();
+        assert_eq!(reader.annotations(), annotations.as_slice());
+    }
+
+    #[test]
+    fn test_deeply_nested_s_expressions() -> IonResult<()> {
+        const DEPTH: usize = 5_000;
+        let text = format!("{}{}{} 6", "(".repeat(DEPTH), 5, ")".repeat(DEPTH));
+        let reader = &mut RawTextReader::new(text.as_str());
+        for depth in 0..DEPTH {
+            assert_eq!(reader.depth(), depth);
+            next_type(reader, IonType::SExpression, false);
+            reader.step_in()?;
+        }
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(5));
+        for _ in 0..DEPTH {
+            reader.step_out()?;
+        }
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(6));
+
+        // Skipping over the nested s-expressions without stepping in also works
+        let reader = &mut RawTextReader::new(text.as_str());
+        next_type(reader, IonType::SExpression, false);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(6));
+        Ok(())
+    }
+
+    #[test]
+    fn test_skipping_containers() -> IonResult<()> {
+        let ion_data = r#"
+            0 [1, 2, 3] (4 5) 6
+        "#;
+        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 0);
+
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 1);
+        reader.step_out()?;
+        // This should have skipped over the `2, 3` at the end of the list.
+        next_type(reader, IonType::SExpression, false);
+        // Don't step into the s-expression. Instead, skip over it.
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_initial_parents_capacity() -> IonResult<()> {
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_initial_parents_capacity(32)
+            .build("[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[]]]]]]]]]]]]]]]]]]]]]]]]]]]]]]]]");
+        let capacity = reader.parents.capacity();
+        assert!(capacity >= 32);
+        for _ in 0..32 {
+            next_type(reader, IonType::List, false);
+            reader.step_in()?;
+        }
+        assert_eq!(reader.depth(), 32);
+        // Stepping in 32 levels deep did not require the parents stack to grow.
+        assert_eq!(reader.parents.capacity(), capacity);
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_sign() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("-5 5 5e+3 [foo::-2.5, +inf] \"-5\"");
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.numeric_sign(),
+            Some(Sign::Explicit(Polarity::Negative))
+        );
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.numeric_sign(), Some(Sign::Implicit));
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.numeric_sign(), Some(Sign::Implicit));
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.numeric_sign(), None);
+        reader.step_in()?;
+        next_type(reader, IonType::Decimal, false);
+        assert_eq!(
+            reader.numeric_sign(),
+            Some(Sign::Explicit(Polarity::Negative))
+        );
+        next_type(reader, IonType::Float, false);
+        assert_eq!(
+            reader.numeric_sign(),
+            Some(Sign::Explicit(Polarity::Positive))
+        );
+        reader.step_out()?;
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.numeric_sign(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_i64_list() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("[1, 2, 3] [] 4 null.list");
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.read_i64_list()?, vec![1, 2, 3]);
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.read_i64_list()?, Vec::<i64>::new());
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.read_i64_list().is_err());
+        next_type(reader, IonType::List, true);
+        assert_eq!(reader.read_i64_list()?, Vec::<i64>::new());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_i64_list_with_non_integer() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("[1, \"x\"]");
+        next_type(reader, IonType::List, false);
+        assert!(reader.read_i64_list().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_f64_list() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("[1.0e0, 2.0e0] [1.0e0, 2]");
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.read_f64_list()?, vec![1.0, 2.0]);
+        next_type(reader, IonType::List, false);
+        assert!(reader.read_f64_list().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_string_list() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(r#""c" ["a", "b"] ["a", b]"#);
+        next_type(reader, IonType::String, false);
+        assert!(reader.read_string_list().is_err());
+        next_type(reader, IonType::List, false);
+        assert_eq!(
+            reader.read_string_list()?,
+            vec!["a".to_string(), "b".to_string()]
+        );
+        next_type(reader, IonType::List, false);
+        assert!(reader.read_string_list().is_err());
+        Ok(())
+    }
+
+    // A `Write` implementation that allows a test to inspect the bytes written to a reader's tee.
+    #[derive(Clone, Default)]
+    struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedSink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_into_source() -> IonResult<()> {
+        let mut reader = RawTextReader::new("1 2\n3 4");
+        next_type(&mut reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(1));
+        let mut source = reader.into_source();
+        // The rest of the first line was buffered by the reader and has been lost
+        assert_eq!(source.position(), 4);
+        let mut remaining = String::new();
+        io::Read::read_to_string(&mut source, &mut remaining)?;
+        assert_eq!(remaining, "3 4");
+        Ok(())
+    }
+
+    #[test]
+    fn test_tee() -> IonResult<()> {
+        let ion_data =
+            "$ion_1_0 // comment\n{a: [1, 2], b: \"two\"} /* skipped */ (3 4)\nfoo::5  \n";
+        let sink = SharedSink::default();
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_tee(sink.clone())
+            .build(ion_data);
+        let teed = || String::from_utf8(sink.0.lock().unwrap().clone()).unwrap();
+
+        assert_eq!(reader.next()?, Some(RawStreamItem::VersionMarker(1, 0)));
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::List, false);
+        // Skip over the list without stepping into it
+        next_type(reader, IonType::String, false);
+        reader.step_out()?;
+        assert_eq!(teed(), &ion_data[..reader.bytes_read()]);
+        // Skip over the s-expression
+        next_type(reader, IonType::SExpression, false);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(teed(), &ion_data[..reader.bytes_read()]);
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.next()?, None);
+        // Once the reader has reached the end of the stream, the tee holds the entire input.
+        assert_eq!(teed(), ion_data);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resync_to_next_top_level() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("5\n@@@ 6\n6\n\n  @@ @@\n7 [8]\n#bad#");
+        next_type(reader, IonType::Integer, false);
+        // Resyncing is only supported after next() fails.
+        assert!(reader.resync_to_next_top_level().is_err());
+        assert!(reader.next().is_err());
+        reader.resync_to_next_top_level()?;
+        // The rest of the malformed line is skipped.
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(6));
+        // Malformed text that contains whitespace is skipped in one call.
+        assert!(reader.next().is_err());
+        reader.resync_to_next_top_level()?;
+        assert!(reader.resync_to_next_top_level().is_err());
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(7));
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        // Resyncing is only supported at the top level.
+        assert!(reader.resync_to_next_top_level().is_err());
+        reader.step_out()?;
+        // Malformed text on the last line is skipped through the end of the stream.
+        assert!(reader.next().is_err());
+        reader.resync_to_next_top_level()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_special_float_keywords() -> IonResult<()> {
+        // Only `+inf`, `-inf`, and `nan` are floats; an unsigned `inf` is an ordinary symbol.
+        let reader = &mut RawTextReader::new("inf +inf -inf nan inf::1 {inf: 5, 'nan': 6}");
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("inf")));
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?, Some(f64::INFINITY));
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?, Some(f64::NEG_INFINITY));
+        next_type(reader, IonType::Float, false);
+        assert!(reader.read_f64()?.unwrap().is_nan());
+        next_type(reader, IonType::Integer, false);
+        annotations_eq(reader, &["inf"]);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("inf")));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("nan")));
+        reader.step_out()?;
+
+        // `nan` is a keyword; like `true` and `null`, it must be quoted to be used as a field name
+        // or annotation. `+inf` and `-inf` are never symbols.
+        for text in ["{nan: 1}", "{+inf: 1}", "{-inf: 1}"] {
+            let reader = &mut RawTextReader::new(text);
+            next_type(reader, IonType::Struct, false);
+            reader.step_in()?;
+            assert!(reader.next().is_err(), "{} was accepted", text);
+        }
+        for text in ["nan::1", "+inf::1", "-inf::1"] {
+            let reader = &mut RawTextReader::new(text);
+            assert!(reader.next().is_err(), "{} was accepted", text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_sequence() -> IonResult<()> {
+        let reader =
+            &mut RawTextReader::new("[1, 2, 3] (a b) [[1], [], [2, 3]] null.sexp [1, two] 5");
+        reader.next()?;
+        let values = reader.read_sequence(|reader| Ok(reader.read_i64()?.unwrap()))?;
+        assert_eq!(values, vec![1, 2, 3]);
+        reader.next()?;
+        let values = reader.read_sequence(|reader| reader.read_symbol())?;
+        assert_eq!(values, vec![Some(text_token("a")), Some(text_token("b"))]);
+        // Sequences can be read recursively
+        reader.next()?;
+        let values = reader.read_sequence(|reader| reader.read_i64_list())?;
+        assert_eq!(values, vec![vec![1], vec![], vec![2, 3]]);
+        reader.next()?;
+        let values = reader.read_sequence(|reader| reader.read_i64())?;
+        assert!(values.is_empty());
+        // Errors returned by the closure are returned by read_sequence
+        reader.next()?;
+        let result = reader.read_sequence(|reader| match reader.read_i64()? {
+            Some(value) => Ok(value),
+            None => decoding_error("expected an integer"),
+        });
+        assert!(result.is_err());
+        reader.step_out()?;
+        // The reader must be positioned over a list or s-expression
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.read_sequence(|reader| reader.read_i64()).is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("2021-01-01T00:00:00Z", Some(1_609_459_200_000))]
+    #[case("2021-01-01T00:00Z", Some(1_609_459_200_000))]
+    #[case("2021-01-01T01:00:00.123+01:00", Some(1_609_459_200_123))]
+    #[case("1969-12-31T23:59:59Z", Some(-1_000))]
+    #[case("null.timestamp", None)]
+    #[case("5", None)]
+    fn test_read_epoch_millis(#[case] text: &str, #[case] expected: Option<i64>) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert!(reader.next()?.is_some());
+        assert_eq!(reader.read_epoch_millis()?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("2021T", "a time of day is required")]
+    #[case("2021-01T", "a time of day is required")]
+    #[case("2021-01-01T", "a time of day is required")]
+    #[case("2021-01-01T00:00:00-00:00", "an unknown offset")]
+    fn test_read_epoch_millis_error(
+        #[case] text: &str,
+        #[case] expected_message: &str,
+    ) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert!(reader.next()?.is_some());
+        match reader.read_epoch_millis() {
+            Err(IonError::IllegalOperation { operation }) => {
+                assert!(operation.contains(expected_message), "{}", operation)
+            }
+            other => panic!("expected an illegal operation error, found {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("-5", Some((CoefficientSign::Negative, 5u64)))]
+    #[case("0", Some((CoefficientSign::Positive, 0u64)))]
+    #[case("-0", Some((CoefficientSign::Positive, 0u64)))]
+    #[case("0x7f", Some((CoefficientSign::Positive, 127u64)))]
+    #[case("-9223372036854775808", Some((CoefficientSign::Negative, 9223372036854775808u64)))]
+    #[case("null.int", None)]
+    #[case("5.0", None)]
+    fn test_read_int_parts(
+        #[case] text: &str,
+        #[case] expected: Option<(CoefficientSign, u64)>,
+    ) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert!(reader.next()?.is_some());
+        let expected = expected.map(|(sign, magnitude)| (sign, BigUint::from(magnitude)));
+        assert_eq!(reader.read_int_parts()?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("true", Some(true), Some(Some(true)), false)]
+    #[case("false", Some(false), Some(Some(false)), false)]
+    #[case("null.bool", None, Some(None), true)]
+    #[case("null", None, None, true)]
+    #[case("5", None, None, false)]
+    fn test_read_bool_opt(
+        #[case] text: &str,
+        #[case] expected_bool: Option<bool>,
+        #[case] expected_bool_opt: Option<Option<bool>>,
+        #[case] is_null: bool,
+    ) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert!(reader.next()?.is_some());
+        assert_eq!(reader.is_null(), is_null);
+        assert_eq!(reader.read_bool()?, expected_bool);
+        assert_eq!(reader.read_bool_opt()?, expected_bool_opt);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("timestamp::2021T", &["timestamp"], true)]
+    #[case("a::b::5", &["a", "b"], true)]
+    #[case("a::$10::5", &["a", "$10"], true)]
+    #[case("5", &[], true)]
+    #[case("b::a::5", &["a", "b"], false)]
+    #[case("a::b::5", &["a"], false)]
+    #[case("a::5", &["a", "b"], false)]
+    #[case("5", &["a"], false)]
+    fn test_expect_annotations(
+        #[case] text: &str,
+        #[case] expected: &[&str],
+        #[case] is_match: bool,
+    ) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert!(reader.next()?.is_some());
+        let result = reader.expect_annotations(expected);
+        assert_eq!(result.is_ok(), is_match);
+        if let Err(IonError::DecodingError { description }) = result {
+            // The error names both the expected and the actual annotations
+            assert!(description.contains(&format!("{:?}", expected)));
+            let actual: Vec<String> = reader
+                .annotations()
+                .iter()
+                .map(super::symbol_token_text)
+                .collect();
+            assert!(description.contains(&format!("{:?}", actual)));
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("9223372036854775807", i64::MAX)]
+    #[case("-9223372036854775808", i64::MIN)]
+    #[case("0x7fff_ffff_ffff_ffff", i64::MAX)]
+    #[case("-0x8000_0000_0000_0000", i64::MIN)]
+    fn test_read_i64_boundaries(#[case] text: &str, #[case] expected: i64) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(expected));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("9223372036854775808")]
+    #[case("-9223372036854775809")]
+    #[case("[9223372036854775808]")]
+    fn test_read_i64_overflow(#[case] text: &str) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        let result = next_stepping_in(reader);
+        match result {
+            Err(IonError::DecodingError { description }) => {
+                assert!(description.contains("overflows an i64"), "{}", description)
+            }
+            other => panic!("expected an overflow error, found {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::huge_positive("9223372036854775808", i64::MAX)]
+    #[case::huge_negative("-99999999999999999999999", i64::MIN)]
+    #[case::hex("0x1_0000_0000_0000_0000", i64::MAX)]
+    #[case::in_list("[9223372036854775808]", i64::MAX)]
+    #[case::in_sexp("(-9223372036854775809)", i64::MIN)]
+    #[case::in_struct("{a: 9223372036854775808}", i64::MAX)]
+    fn test_read_i64_clamped(#[case] text: &str, #[case] expected: i64) -> IonResult<()> {
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_integer_overflow_mode(IntegerOverflowMode::Clamp)
+            .build(text);
+        assert_eq!(
+            next_stepping_in(reader)?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64_clamped()?, Some(expected));
+        // `read_i64` does not clamp
+        assert!(reader.read_i64().is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::huge_positive("99999999999999999999", "99999999999999999999")]
+    #[case::huge_negative("-9223372036854775809", "-9223372036854775809")]
+    #[case::hex("0x1_0000_0000_0000_0000", "18446744073709551616")]
+    #[case::binary(
+        "-0b1_0000000000000000000000000000000000000000000000000000000000000000",
+        "-18446744073709551616"
+    )]
+    fn test_clamp_mode_preserves_exact_integers(
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let build = || {
+            RawTextReaderBuilder::new()
+                .with_integer_overflow_mode(IntegerOverflowMode::Clamp)
+                .build(text)
+        };
+        let expected: BigInt = expected.parse().unwrap();
+
+        let reader = &mut build();
+        next_type(reader, IonType::Integer, false);
+        let (sign, magnitude) = reader.read_int_parts()?.unwrap();
+        assert_eq!(magnitude, *expected.magnitude());
+        let expected_sign = match expected.sign() {
+            BigIntSign::Minus => CoefficientSign::Negative,
+            _ => CoefficientSign::Positive,
+        };
+        assert_eq!(sign, expected_sign);
+        assert_eq!(reader.read_as_string()?, Some(expected.to_string()));
+        assert!(reader.read_i64_annotated().is_err());
+        assert_eq!(
+            *reader.read_element()?.value(),
+            OwnedValue::Integer(AnyInt::BigInt(expected.clone()))
+        );
+
+        // Re-serializing the stream writes the exact value
+        assert_eq!(build().to_pretty_string()?.trim(), expected.to_string());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("5", 5)]
+    #[case("9223372036854775807", i64::MAX)]
+    #[case("-9223372036854775808", i64::MIN)]
+    fn test_read_i64_clamped_in_range(#[case] text: &str, #[case] expected: i64) -> IonResult<()> {
+        for mode in [IntegerOverflowMode::Error, IntegerOverflowMode::Clamp] {
+            let reader = &mut RawTextReaderBuilder::new()
+                .with_integer_overflow_mode(mode)
+                .build(text);
+            next_type(reader, IonType::Integer, false);
+            assert_eq!(reader.read_i64_clamped()?, Some(expected));
+            assert_eq!(reader.read_i64()?, Some(expected));
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("{{aGVsbG8=}}", Some("aGVsbG8="))]
+    #[case("{{ aGVsbG8= }}", Some("aGVsbG8="))]
+    #[case("{{\"hello\"}}", Some("aGVsbG8="))]
+    #[case("{{'''hel''' '''lo'''}}", Some("aGVsbG8="))]
+    #[case("{{\"\\xff\\x00\"}}", Some("/wA="))]
+    #[case("{{}}", Some(""))]
+    #[case("null.blob", None)]
+    #[case("\"hello\"", None)]
+    fn test_read_lob_base64(#[case] text: &str, #[case] expected: Option<&str>) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert!(reader.next()?.is_some());
+        assert_eq!(reader.read_lob_base64()?.as_deref(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_comment_markers_inside_literals() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(
+            "\"http://x\" // real comment\n 5 'a//b' /* block */ '''c/*d''' [\"e//\"] 6",
+        );
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, Some("http://x".to_string()));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(5));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("a//b")));
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, Some("c/*d".to_string()));
+        // Skipping a container must not treat the `//` in its string as a comment
+        next_type(reader, IonType::List, false);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(6));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_f64_is_correctly_rounded() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("3.141592653589793e0 0.1e0 2.2250738585072011e-308");
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?, Some(std::f64::consts::PI));
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?, Some(0.1));
+        // A subnormal value that is notoriously difficult to round correctly.
+        next_type(reader, IonType::Float, false);
+        assert_eq!(
+            reader.read_f64()?.map(f64::to_bits),
+            Some(0x000F_FFFF_FFFF_FFFF)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbols_as_strings() -> IonResult<()> {
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_symbols_as_strings(true)
+            .build("foo 'bar baz' a::b {c: d} $10 null.symbol \"e\"");
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, Some("foo".to_string()));
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, Some("bar baz".to_string()));
+        // Annotations are still symbols
+        next_type(reader, IonType::String, false);
+        annotations_eq(reader, &["a"]);
+        assert_eq!(reader.read_string()?, Some("b".to_string()));
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        // Field names are still symbols
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.field_name(), Some(&text_token("c")));
+        assert_eq!(reader.read_string()?, Some("d".to_string()));
+        reader.step_out()?;
+        // Symbol IDs have no known text, so they remain symbols
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(local_sid_token(10)));
+        next_type(reader, IonType::String, true);
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, Some("e".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_single_struct() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("$ion_1_0 {\n  a: 1,\n  b: [2]\n} // comment\n");
+        reader.read_single_struct()?;
+        assert_eq!(reader.depth(), 1);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("a")));
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.field_name(), Some(&text_token("b")));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::trailing_value("{a:1} 5")]
+    #[case::trailing_struct("{a:1}\n{b:2}")]
+    #[case::not_a_struct("[1, 2]")]
+    #[case::null_struct("null.struct")]
+    #[case::empty("// nothing here")]
+    #[case::unterminated("{a:1")]
+    fn test_read_single_struct_errors(#[case] text: &str) {
+        let reader = &mut RawTextReader::new(text);
+        assert!(reader.read_single_struct().is_err());
+    }
+
+    #[test]
+    fn test_read_single_struct_not_at_top_level() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a:{b:1}}");
+        reader.read_single_struct()?;
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        assert!(reader.read_single_struct().is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::decimal("1.5", IonType::Decimal)]
+    #[case::float("1.5e0", IonType::Float)]
+    #[case::decimal_in_list("[1.5]", IonType::List)]
+    fn test_decimal_float_disambiguation(#[case] text: &str, #[case] expected_type: IonType) {
+        let reader = &mut RawTextReader::new(text);
+        next_type(reader, expected_type, false);
+        assert_eq!(reader.next().unwrap(), None);
+    }
+
+    #[rstest]
+    #[case::top_level("1.5e")]
+    #[case::top_level_followed_by_value("1.5e 2")]
+    #[case::in_list("[1.5e]")]
+    fn test_incomplete_float_exponent_is_an_error(#[case] text: &str) {
+        let reader = &mut RawTextReader::new(text);
+        let mut result = reader.next();
+        if let Ok(Some(RawStreamItem::Value(IonType::List, false))) = result {
+            reader.step_in().unwrap();
+            result = reader.next();
+        }
+        let error = result.expect_err("`1.5e` was not rejected").to_string();
+        assert!(error.contains("1.5e"), "{}", error);
+    }
+
+    #[test]
+    fn test_clear_current() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("5 {a: [6]} 7");
+        next_type(reader, IonType::Integer, false);
+        reader.clear_current()?;
+        assert_eq!(reader.ion_type(), None);
+        assert!(!reader.is_null());
+        assert_eq!(reader.read_i64()?, None);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.field_name(), Some(&text_token("a")));
+        // Clearing a container skips its contents
+        reader.clear_current()?;
+        assert_eq!(reader.ion_type(), None);
+        assert_eq!(reader.field_name(), None);
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(7));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_field() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a:1, b:\"x\", c: true, d: 2.5e0} {a:1, b:2}");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        assert_eq!(reader.read_field_i64("a")?, Some(1));
+        assert_eq!(reader.read_field_string("b")?, Some("x".to_string()));
+        // Skips over field `c`
+        assert_eq!(reader.read_field_f64("d")?, Some(2.5));
+        // Fields that have already been passed are not found
+        assert_eq!(reader.read_field_bool("c")?, None);
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        // A field whose value has a different type
+        assert_eq!(reader.read_field_string("b")?, None);
+        reader.step_out()?;
+
+        // Not inside a struct
+        assert!(reader.read_field_i64("a").is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::string("\"hello\"", 7)]
+    #[case::annotated_string(" /*c*/ a::b::\"hello\" ", 7)]
+    #[case::integer("5 ", 1)]
+    #[case::integer_at_eof("-123", 4)]
+    #[case::float("1.5e0", 5)]
+    #[case::symbol("foo", 3)]
+    #[case::quoted_symbol("'foo bar'", 9)]
+    #[case::null("null.int", 8)]
+    #[case::null_container("null.list", 9)]
+    #[case::blob("{{aGVsbG8=}}", 12)]
+    #[case::long_string("'''ab''' '''cd''' 1", 17)]
+    #[case::timestamp("2021-01-01T ", 11)]
+    fn test_current_value_byte_len(#[case] text: &str, #[case] expected: usize) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert_eq!(reader.current_value_byte_len(), None);
+        reader.next()?;
+        assert_eq!(reader.current_value_byte_len(), Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_current_value_byte_len_in_containers() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("[1, \"two\"] (foo + bar)");
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.current_value_byte_len(), None);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.current_value_byte_len(), Some(1));
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.current_value_byte_len(), Some(5));
+        reader.step_out()?;
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.current_value_byte_len(), Some(3));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.current_value_byte_len(), Some(1));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.current_value_byte_len(), Some(3));
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.current_value_byte_len(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_quoted_operator_symbol() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("'+-*/' ('+' '+-*/' '\\x2b\\x2b' ++)");
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("+-*/")));
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        // Each quoted symbol is a single value rather than a run of operators
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("+")));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("+-*/")));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("++")));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("++")));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::none("5", 0)]
+    #[case::one("a::5", 1)]
+    #[case::several("a::b::c::5", 3)]
+    #[case::repeated("a::a::5", 2)]
+    #[case::container("a::b::[1]", 2)]
+    fn test_annotation_count(#[case] text: &str, #[case] expected: usize) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert_eq!(reader.annotation_count(), 0);
+        reader.next()?;
+        assert_eq!(reader.annotation_count(), expected);
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.annotation_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotations_text() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("a::a::b::5 $10::a::[] 6");
+        assert_eq!(reader.annotations_text().count(), 0);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.annotations_text().collect::<Vec<_>>(),
+            [Some("a"), Some("a"), Some("b")]
+        );
+        next_type(reader, IonType::List, false);
+        assert_eq!(
+            reader.annotations_text().collect::<Vec<_>>(),
+            [None, Some("a")]
+        );
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.annotations_text().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_symbol_id() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{$10: 1, foo: 2, '$11': 3} 4");
+        next_type(reader, IonType::Struct, false);
+        assert_eq!(reader.field_symbol_id(), None);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_symbol_id(), Some(10));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_symbol_id(), None);
+        // A quoted `$11` is text, not a symbol ID
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_symbol_id(), None);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_symbol_id(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_container_predicates() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: [(1)]}");
+        let in_containers =
+            |reader: &RawTextReader<&str>| (reader.in_struct(), reader.in_list(), reader.in_sexp());
+        assert_eq!(in_containers(reader), (false, false, false));
+        next_type(reader, IonType::Struct, false);
+        // Being on a container is not the same as being inside it
+        assert_eq!(in_containers(reader), (false, false, false));
+        reader.step_in()?;
+        assert_eq!(in_containers(reader), (true, false, false));
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        assert_eq!(in_containers(reader), (false, true, false));
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        assert_eq!(in_containers(reader), (false, false, true));
+        reader.step_out()?;
+        assert_eq!(in_containers(reader), (false, true, false));
+        reader.step_out()?;
+        assert_eq!(in_containers(reader), (true, false, false));
+        reader.step_out()?;
+        assert_eq!(in_containers(reader), (false, false, false));
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_until_annotation() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("a::1 b::2 c::3");
+        assert_eq!(reader.skip_until_annotation("b")?, Some(()));
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.skip_until_annotation("b")?, None);
+        assert_eq!(reader.next()?, None);
+
+        // Containers are skipped, and values can have more than one annotation
+        let reader = &mut RawTextReader::new("[x::1, {y: x::2}] (x::3) y::x::4 x::5");
+        assert_eq!(reader.skip_until_annotation("x")?, Some(()));
+        assert_eq!(reader.read_i64()?, Some(4));
+
+        // Only searches the current depth
+        let reader = &mut RawTextReader::new("[1, a::2, 3] a::4");
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        assert_eq!(reader.skip_until_annotation("a")?, Some(()));
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.skip_until_annotation("a")?, None);
+        reader.step_out()?;
+        assert_eq!(reader.skip_until_annotation("a")?, Some(()));
+        assert_eq!(reader.read_i64()?, Some(4));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::tight("{a:1,b:2}")]
+    #[case::spaced("{a: 1, b: 2}")]
+    #[case::space_before_colon("{a :1,b :2}")]
+    #[case::loose("{ a : 1 , b : 2 }")]
+    #[case::newlines("{\na\n:\n1\n,\nb\n:\n2\n}")]
+    #[case::comments("{a/*x*/:/*y*/1 /*z*/,b://w\n2}")]
+    #[case::trailing_comma("{a:1,b:2,}")]
+    fn test_struct_field_whitespace(#[case] text: &str) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("a")));
+        assert_eq!(reader.read_i64()?, Some(1));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("b")));
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_optional() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("null.int 5 null.string null");
+        next_type(reader, IonType::Integer, true);
+        assert_eq!(reader.read_optional(RawTextReader::read_i64)?, None);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_optional(RawTextReader::read_i64)?, Some(5));
+        // Nulls of other types are also None
+        next_type(reader, IonType::String, true);
+        assert_eq!(reader.read_optional(RawTextReader::read_i64)?, None);
+        next_type(reader, IonType::Null, true);
+        assert_eq!(reader.read_optional(RawTextReader::read_i64)?, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::top_level("2021-01-01T00:00:00.500-05:00", "2021-01-01T00:00:00.500-05:00")]
+    #[case::followed_by_value("2021T 5", "2021T")]
+    #[case::annotated(" /*c*/ a::2021-01T\n", "2021-01T")]
+    #[case::utc("[2021-01-01T00:00Z, 1]", "2021-01-01T00:00Z")]
+    #[case::in_struct("{t:2021-01-01T00:00:00+00:00}", "2021-01-01T00:00:00+00:00")]
+    #[case::in_sexp("(2021T)", "2021T")]
+    fn test_timestamp_source_text(#[case] text: &str, #[case] expected: &str) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert_eq!(
+            next_stepping_in(reader)?,
+            Some(RawStreamItem::Value(IonType::Timestamp, false))
+        );
+        assert_eq!(reader.timestamp_source_text(), Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_source_text_not_a_timestamp() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("5 null.timestamp");
+        assert_eq!(reader.timestamp_source_text(), None);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.timestamp_source_text(), None);
+        next_type(reader, IonType::Timestamp, true);
+        assert_eq!(reader.timestamp_source_text(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_by_annotation() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("a::1 b::2 a::3");
+        let index = reader.index_by_annotation()?;
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), vec![0, 2]);
+        expected.insert("b".to_string(), vec![1]);
+        assert_eq!(index, expected);
+        assert_eq!(reader.next()?, None);
+
+        let reader = &mut RawTextReader::new(
+            "0 $ion_1_0 a::b::[c::1] a::a::{} $10::null 'b'::5 $ion_1_0 c::d",
+        );
+        // Indices start at the reader's current position
+        next_type(reader, IonType::Integer, false);
+        let index = reader.index_by_annotation()?;
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), vec![0, 1]);
+        expected.insert("b".to_string(), vec![0, 3]);
+        expected.insert("$10".to_string(), vec![2]);
+        expected.insert("c".to_string(), vec![4]);
+        assert_eq!(index, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::negative_hex("-0xFF", -255)]
+    #[case::negative_binary("-0b101", -5)]
+    #[case::negative_hex_in_list("[-0xFF]", -255)]
+    fn test_read_negative_radix_integers(#[case] text: &str, #[case] expected: i64) {
+        let reader = &mut RawTextReader::new(text);
+        assert_eq!(
+            next_stepping_in(reader).unwrap(),
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64().unwrap(), Some(expected));
+    }
+
+    #[rstest]
+    #[case::hex("0x-1")]
+    #[case::binary("0b-101")]
+    #[case::hex_followed_by_value("0x-FF 5")]
+    fn test_sign_after_radix_prefix_is_an_error(#[case] text: &str) {
+        let reader = &mut RawTextReader::new(text);
+        let error = reader
+            .next()
+            .expect_err("sign after radix prefix was accepted");
+        assert!(
+            error.to_string().contains("malformed numeric value"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_stats() -> IonResult<()> {
+        let text = "1 {a: [2, 3], b: null}\n[4, 5] (6)\n7";
+        let reader = &mut RawTextReader::new(text);
+        assert_eq!(reader.stats(), ReaderStats::default());
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::Integer, false);
+        reader.step_out()?;
+        next_type(reader, IonType::Null, true);
+        reader.step_out()?;
+        // The list is skipped without being stepped into
+        next_type(reader, IonType::List, false);
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.next()?, None);
+        assert_eq!(
+            reader.stats(),
+            ReaderStats {
+                scalars_read: 6,
+                containers_entered: 3,
+                bytes_consumed: text.len(),
+                lines_consumed: 2,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_pretty_string() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("$ion_1_0 {a:[1,2]} foo::(b -0.0)");
+        let expected = "{\n  a: [\n    1,\n    2\n  ]\n}\n'foo'::(\n  'b'\n  -0.0\n)\n";
+        assert_eq!(reader.to_pretty_string()?, expected);
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_pretty_string_symbol_ids() -> IonResult<()> {
+        // Symbol IDs are written unquoted everywhere; text that looks like a symbol ID is quoted.
+        let reader = &mut RawTextReader::new("{$10: $11::$12, '$13': '$14'::'$15'}");
+        let expected = "{\n  $10: $11::$12,\n  '$13': '$14'::'$15'\n}\n";
+        assert_eq!(reader.to_pretty_string()?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("{'a b': 'it\\'s'}")]
+    #[case("{'null': null, '$10': '+', $10: a}")]
+    #[case("'x y'::'true'::\"say \\\"hi\\\"\\n\"")]
+    #[case("['a\\\\b', 'tab\\t', '']")]
+    #[case("(+ '+' a::- 'caf\u{e9}')")]
+    fn test_to_pretty_string_round_trip(#[case] text: &str) -> IonResult<()> {
+        let pretty = RawTextReader::new(text).to_pretty_string()?;
+        assert!(
+            equivalent_streams(
+                &mut SystemReader::new(RawTextReader::new(text)),
+                &mut SystemReader::new(RawTextReader::new(pretty.as_str())),
+            )?,
+            "{} was printed as {}",
+            text,
+            pretty
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_lines() -> IonResult<()> {
+        let lines = vec!["[1,", "2]", "3"]
+            .into_iter()
+            .map(|line| Ok(line.to_string()));
+        let mut reader = RawTextReader::from_lines(lines);
+        assert_eq!(
+            reader.next()?,
+            Some(RawStreamItem::Value(IonType::List, false))
+        );
+        assert_eq!(reader.read_i64_list()?, vec![1, 2]);
+        assert_eq!(
+            reader.next()?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64()?, Some(3));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_lines_error() -> IonResult<()> {
+        let lines = vec![
+            Ok("1 [2,".to_string()),
+            decoding_error("the channel was closed"),
+        ];
+        let mut reader = RawTextReader::from_lines(lines.into_iter());
+        assert_eq!(
+            reader.next()?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64()?, Some(1));
+        assert_eq!(
+            reader.next()?,
+            Some(RawStreamItem::Value(IonType::List, false))
+        );
+        reader.step_in()?;
+        assert_eq!(
+            reader.next()?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        // Reading past `2` requires another line, but the iterator yields an error instead
+        match reader.next() {
+            Err(e) => assert!(format!("{:?}", e).contains("the channel was closed")),
+            other => panic!("expected an error, found {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_if() -> IonResult<()> {
+        let is_int = |ion_type, _is_null| ion_type == IonType::Integer;
+        let reader = &mut RawTextReader::new(r#"5 "x""#);
+        assert_eq!(
+            reader.next_if(is_int)?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64()?, Some(5));
+        // The string is not consumed and the reader has no current value
+        assert_eq!(reader.next_if(is_int)?, None);
+        assert_eq!(reader.ion_type(), None);
+        assert_eq!(reader.next_if(is_int)?, None);
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?, Some("x".to_string()));
+        assert_eq!(reader.next_if(is_int)?, None);
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_if_counts_scalars_when_returned() -> IonResult<()> {
+        let is_int = |ion_type, _is_null| ion_type == IonType::Integer;
+        let reader = &mut RawTextReader::new(r#"5 "x""#);
+        reader.next_if(is_int)?;
+        assert_eq!(reader.stats().scalars_read, 1);
+        // Declining the string doesn't count it, however many times it's declined
+        assert_eq!(reader.next_if(is_int)?, None);
+        assert_eq!(reader.next_if(is_int)?, None);
+        assert_eq!(reader.stats().scalars_read, 1);
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.stats().scalars_read, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_if_in_struct() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: [1, 2], b: 3}");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        assert_eq!(
+            reader.next_if(|ion_type, _| ion_type == IonType::Integer)?,
+            None
+        );
+        assert_eq!(reader.field_name(), None);
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.field_name(), Some(&text_token("a")));
+        assert_eq!(reader.read_i64_list()?, vec![1, 2]);
+        // A declined value can be skipped by step_out()
+        assert_eq!(
+            reader.next_if(|ion_type, _| ion_type == IonType::List)?,
+            None
+        );
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_current_info() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{x: foo::bar::5} null");
+        assert_eq!(reader.current_info(), None);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.current_info(),
+            Some(ValueInfo {
+                ion_type: IonType::Integer,
+                is_null: false,
+                depth: 1,
+                annotation_count: 2,
+                field_name: Some(text_token("x")),
+            })
+        );
+        reader.step_out()?;
+        next_type(reader, IonType::Null, true);
+        assert_eq!(
+            reader.current_info(),
+            Some(ValueInfo {
+                ion_type: IonType::Null,
+                is_null: true,
+                depth: 0,
+                annotation_count: 0,
+                field_name: None,
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_map() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: 1, a: 2, b: [3]} null.struct 4");
+        next_type(reader, IonType::Struct, false);
+        let map = reader.read_map()?;
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], OwnedElement::from(2i64));
+        assert_eq!(
+            map["b"],
+            OwnedElement::new_list(vec![OwnedElement::from(3i64)])
+        );
+        next_type(reader, IonType::Struct, true);
+        assert!(reader.read_map()?.is_empty());
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.read_map().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_map_symbol_id_field_name() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: 1, $10: 2}");
+        next_type(reader, IonType::Struct, false);
+        assert!(matches!(
+            reader.read_map(),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_with_escapes() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(r"'a\tb'::5");
+        next_type(reader, IonType::Integer, false);
+        annotations_eq(reader, &["a\tb"]);
+        // The escape sequence has been replaced with an actual tab character
+        assert_eq!(reader.annotations()[0].text(), Some("a\u{9}b"));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::spaces("foo :: 5")]
+    #[case::comment("foo /* c */ :: 5")]
+    #[case::newline("foo\n::\n5")]
+    #[case::quoted("'foo' :: 5")]
+    #[case::symbol_id("$10 :: 5")]
+    #[case::in_list("[foo :: 5]")]
+    #[case::in_struct("{a: foo :: 5}")]
+    #[case::in_s_expression("(foo :: 5)")]
+    fn test_whitespace_before_annotation_delimiter(#[case] text: &str) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        assert_eq!(
+            next_stepping_in(reader)?,
+            Some(RawStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.annotations().len(), 1);
+        assert_eq!(reader.read_i64()?, Some(5));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::integer("5::6", "'5'")]
+    #[case::string("\"str\"::6", "'\"str\"'")]
+    #[case::null("null::5", "'null'")]
+    #[case::boolean("true::5", "'true'")]
+    #[case::after_symbol_annotation("a::\"str\"::6", "\"str\"")]
+    #[case::in_list("[1, 5::6]", "'5'")]
+    #[case::in_struct("{a: 5::6}", "'5'")]
+    #[case::in_s_expression("(\"str\"::6)", "'\"str\"'")]
+    fn test_non_symbol_annotation_is_an_error(#[case] text: &str, #[case] expected_text: &str) {
+        let reader = &mut RawTextReader::new(text);
+        let mut result = next_stepping_in(reader);
+        while let Ok(Some(_)) = result {
+            result = reader.next();
+        }
+        let error = result.expect_err("annotation was not rejected").to_string();
+        assert!(error.contains("non-symbol annotation"), "{}", error);
+        assert!(error.contains(expected_text), "{}", error);
+    }
+
+    #[test]
+    fn test_quoted_keyword_annotation() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("'null'::5 'true'::6");
+        next_type(reader, IonType::Integer, false);
+        annotations_eq(reader, &["null"]);
+        next_type(reader, IonType::Integer, false);
+        annotations_eq(reader, &["true"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotations_raw() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("a::$10::5 6");
+        assert!(reader.annotations_raw().is_empty());
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.annotations_raw(),
+            &[text_token("a"), local_sid_token(10)]
+        );
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.annotations_raw().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_field() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: 1, b: [2], $10: null.string, c: {d: 3}} 4");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        assert_eq!(reader.next_field()?, Some((Some("a"), IonType::Integer)));
+        assert_eq!(reader.read_i64()?, Some(1));
+        assert_eq!(reader.next_field()?, Some((Some("b"), IonType::List)));
+        // Step into the field's value
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(2));
+        reader.step_out()?;
+        assert_eq!(reader.next_field()?, Some((None, IonType::String)));
+        // Skip over a container field without stepping into it
+        assert_eq!(reader.next_field()?, Some((Some("c"), IonType::Struct)));
+        assert_eq!(reader.next_field()?, None);
+        reader.step_out()?;
+
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.next_field().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_entries() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: 1, b: 2} 3");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        let entries: IonResult<Vec<_>> = reader.struct_entries().collect();
+        assert_eq!(
+            entries?,
+            vec![
+                (
+                    Some("a".to_string()),
+                    RawStreamItem::Value(IonType::Integer, false)
+                ),
+                (
+                    Some("b".to_string()),
+                    RawStreamItem::Value(IonType::Integer, false)
+                ),
+            ]
+        );
+        // The reader is still inside the struct
+        assert_eq!(reader.depth(), 1);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.struct_entries().next().unwrap().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lob_bytes() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{{aGVsbG8=}} {{\"world\"}} \"foo\" null.blob");
+        next_type(reader, IonType::Blob, false);
+        assert_eq!(
+            reader.read_lob_bytes()?,
+            Some((IonType::Blob, "hello".as_bytes()))
+        );
+        next_type(reader, IonType::Clob, false);
+        assert_eq!(
+            reader.read_lob_bytes()?,
+            Some((IonType::Clob, "world".as_bytes()))
+        );
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_lob_bytes()?, None);
+        next_type(reader, IonType::Blob, true);
+        assert_eq!(reader.read_lob_bytes()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_string_field_names() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(r#"{"a b": 1, 'c': 2, d: 3, "e\tf": 4}"#);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        for (field_name, value) in &[("a b", 1), ("c", 2), ("d", 3), ("e\tf", 4)] {
+            next_type(reader, IonType::Integer, false);
+            assert_eq!(reader.field_name(), Some(&text_token(*field_name)));
+            assert_eq!(reader.read_i64()?, Some(*value));
+        }
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_forbid_top_level_symbols() -> IonResult<()> {
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_forbid_top_level_symbols(true)
+            .build("{a: foo} 'bar'::5 [baz] foo");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("foo")));
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::List, false);
+        assert!(reader.next().is_err());
+
+        // Top-level symbols are allowed by default
+        let reader = &mut RawTextReader::new("foo");
+        next_type(reader, IonType::Symbol, false);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1.2.3")]
+    #[case("1e")]
+    #[case("1e+")]
+    #[case("0x")]
+    #[case("0b2")]
+    #[case("-0x_1")]
+    fn test_read_malformed_numeric(#[case] text: &str) {
+        let expect_error = |reader: &mut RawTextReader<&str>| match reader.next() {
+            Err(IonError::DecodingError { description }) => {
+                assert!(
+                    description.contains("malformed numeric value")
+                        && description.contains(&format!("'{}'", text)),
+                    "Unexpected error message: {}",
+                    description
+                );
+            }
+            other => panic!("Expected a decoding error, but got: {:?}", other),
+        };
+        // At the top level...
+        expect_error(&mut RawTextReader::new(text));
+        // ...followed by another value...
+        let input = format!("{} 5", text);
+        expect_error(&mut RawTextReader::new(input.as_str()));
+        // ...and inside a list.
+        let input = format!("[{}]", text);
+        let reader = &mut RawTextReader::new(input.as_str());
+        next_type(reader, IonType::List, false);
+        reader.step_in().unwrap();
+        expect_error(reader);
+    }
+
+    #[rstest]
+    #[case("2021-13-01T")]
+    #[case("2021-02-30")]
+    #[case("2021-01-01T25:00Z")]
+    #[case("[2021-01-01T00:00:60Z]")]
+    fn test_read_malformed_timestamp(#[case] text: &str) {
+        let reader = &mut RawTextReader::new(text);
+        match next_stepping_in(reader) {
+            Err(IonError::DecodingError { description }) => {
+                assert!(
+                    description.contains("malformed timestamp"),
+                    "Unexpected error message: {}",
+                    description
+                );
+            }
+            other => panic!("Expected a decoding error, but got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_next_and_read() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("1 2 3");
+        assert_eq!(reader.next_i64()?, Some(1));
+        assert_eq!(reader.next_i64()?, Some(2));
+        assert_eq!(reader.next_i64()?, Some(3));
+        assert_eq!(reader.next_i64()?, None);
+
+        let reader = &mut RawTextReader::new("$ion_1_0 true 2.5e0 \"foo\" null.int foo");
+        assert_eq!(reader.next_bool()?, Some(true));
+        assert_eq!(reader.next_f64()?, Some(2.5));
+        assert_eq!(reader.next_string()?, Some("foo".to_string()));
+        // Nulls are a type mismatch
+        assert!(reader.next_i64().is_err());
+        assert!(reader.next_string().is_err());
+        assert_eq!(reader.next_string()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_reports_current_line() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("1\n2\n3x\n4\n5\n");
+        assert_eq!(reader.next_i64()?, Some(1));
+        assert_eq!(reader.next_i64()?, Some(2));
+        match reader.next() {
+            Err(IonError::DecodingError { description }) => {
+                assert!(
+                    description.contains("near line 3:"),
+                    "Unexpected error message: {}",
+                    description
+                );
+            }
+            other => panic!("Expected a decoding error, but got: {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("[]", IonType::List)]
+    #[case("{}", IonType::Struct)]
+    #[case("()", IonType::SExpression)]
+    #[case("[ /* nothing here */ ]", IonType::List)]
+    #[case("{\n}", IonType::Struct)]
+    #[case("( )", IonType::SExpression)]
+    fn test_read_empty_container(#[case] text: &str, #[case] ion_type: IonType) -> IonResult<()> {
+        let input = format!("{} {} 7", text, text);
+        let reader = &mut RawTextReader::new(input.as_str());
+        // Step into the first container and find that it's empty
+        next_type(reader, ion_type, false);
+        reader.step_in()?;
+        assert_eq!(reader.depth(), 1);
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.ion_type(), None);
+        // Calling next() again continues to return None
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.depth(), 1);
+        reader.step_out()?;
+        assert_eq!(reader.depth(), 0);
+        // Step into the second container and immediately step out again
+        next_type(reader, ion_type, false);
+        reader.step_in()?;
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(7));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_nested_empty_containers() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("[[], (), {}]");
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        for ion_type in &[IonType::List, IonType::SExpression, IonType::Struct] {
+            next_type(reader, *ion_type, false);
+            reader.step_in()?;
+            assert_eq!(reader.depth(), 2);
+            assert_eq!(reader.next()?, None);
+            reader.step_out()?;
+        }
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_interned_symbol() -> IonResult<()> {
+        let ion_data = format!("[{}] $10 \"foo\"", vec!["foo"; 1000].join(", "));
+        let reader = &mut RawTextReader::new(ion_data.as_str());
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        let mut symbols = Vec::new();
+        while let Some(item) = reader.next()? {
+            assert_eq!(item, RawStreamItem::Value(IonType::Symbol, false));
+            symbols.push(reader.read_interned_symbol()?.unwrap());
+        }
+        reader.step_out()?;
+        assert_eq!(symbols.len(), 1000);
+        assert_eq!(&*symbols[0], "foo");
+        assert!(symbols.iter().all(|s| Arc::ptr_eq(s, &symbols[0])));
+
+        next_type(reader, IonType::Symbol, false);
+        assert!(reader.read_interned_symbol().is_err());
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_interned_symbol()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_interned_symbol_cache_is_bounded() -> IonResult<()> {
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_max_interned_symbols(2)
+            .build("a b a c a $ion_1_0 a");
+        fn read_next(reader: &mut RawTextReader<&str>) -> IonResult<Arc<str>> {
+            while let Some(RawStreamItem::VersionMarker(_, _)) = reader.next()? {}
+            Ok(reader.read_interned_symbol()?.unwrap())
+        }
+        let a = read_next(reader)?;
+        read_next(reader)?;
+        assert!(Arc::ptr_eq(&a, &read_next(reader)?));
+        // Caching `c` would exceed the limit, so the cache is discarded first.
+        read_next(reader)?;
+        let second_a = read_next(reader)?;
+        assert!(!Arc::ptr_eq(&a, &second_a));
+        assert_eq!(reader.interned_symbols.len(), 2);
+        // The IVM discards the cache.
+        let third_a = read_next(reader)?;
+        assert!(!Arc::ptr_eq(&second_a, &third_a));
+        assert_eq!(reader.interned_symbols.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_annotated_struct_field_value() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: foo::bar::5, b: baz::{c: quux::6}}");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("a")));
+        annotations_eq(reader, &["foo", "bar"]);
+        assert_eq!(reader.read_i64()?, Some(5));
+        next_type(reader, IonType::Struct, false);
+        assert_eq!(reader.field_name(), Some(&text_token("b")));
+        annotations_eq(reader, &["baz"]);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("c")));
+        annotations_eq(reader, &["quux"]);
+        assert_eq!(reader.read_i64()?, Some(6));
+        reader.step_out()?;
+        reader.step_out()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_nested_containers() -> IonResult<()> {
+        let ion_data = r#"
+            {
+                foo: [
+                    1,
+                    [2, 3],
+                    4
+                ],
+                bar: {
+                    a: 5,
+                    b: (true true true)
+                }
+            }
+            11
+        "#;
+        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        // The reader is now at the '2' nested inside of 'foo'
+        reader.step_out()?;
+        reader.step_out()?;
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Boolean, false);
+        next_type(reader, IonType::Boolean, false);
+        // The reader is now at the second 'true' in the s-expression nested in 'bar'/'b'
+        reader.step_out()?;
+        reader.step_out()?;
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 11);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_container_with_mixed_scalars_and_containers() -> IonResult<()> {
+        let ion_data = r#"
+            {
+                foo: 4,
+                bar: {
+                    a: 5,
+                    b: (true true true)
+                }
+            }
+            42
+        "#;
+
+        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name().unwrap(), &text_token("foo"));
+        next_type(reader, IonType::Struct, false);
+        assert_eq!(reader.field_name().unwrap(), &text_token("bar"));
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 5);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 42);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(" null ", TextValue::Null(IonType::Null))]
+    #[case(" null.string ", TextValue::Null(IonType::String))]
+    #[case(" true ", TextValue::Boolean(true))]
+    #[case(" false ", TextValue::Boolean(false))]
+    #[case(" 738 ", TextValue::Integer(738))]
+    #[case(" 2.5e0 ", TextValue::Float(2.5))]
+    #[case(" 2.5 ", TextValue::Decimal(Decimal::new(25, -1)))]
+    #[case(" 2007-07-12T ", TextValue::Timestamp(Timestamp::with_ymd(2007, 7, 12).build().unwrap()))]
+    #[case(" foo ", TextValue::Symbol(text_token("foo")))]
+    #[case(" \"hi!\" ", TextValue::String("hi!".to_owned()))]
+    #[case(" {{ZW5jb2RlZA==}} ", TextValue::Blob(Vec::from("encoded".as_bytes())))]
+    #[case(" {{\"hello\"}} ", TextValue::UnescapedClob(5))]
+    #[case(" {{\"hello\\n\"}} ", TextValue::Clob(Vec::from("hello\n".as_bytes())))]
+    fn test_read_single_top_level_values(#[case] text: &str, #[case] expected_value: TextValue) {
+        let reader = &mut RawTextReader::new(text);
+        next_type(
+            reader,
+            expected_value.ion_type(),
+            matches!(expected_value, TextValue::Null(_)),
+        );
+        // TODO: Redo (or remove?) this test. There's not an API that exposes the
+        //       AnnotatedTextValue any more. We're directly accessing `current_value` as a hack.
+        let actual_value = reader.current_value.clone();
+        assert_eq!(actual_value.unwrap(), expected_value.without_annotations());
+    }
+
+    #[test]
+    fn test_text_read_multiple_top_level_values() -> IonResult<()> {
+        let ion_data = r#"
+            null
+            true
+            5
+            5e0
+            5.5
+            2021-09-25T
+            '$ion_1_0' // A quoted symbol, not an IVM
+            $ion_1_0   // An IVM, not a symbol
+            foo
+            "hello"
+            {foo: bar}
+            ["foo", "bar"]
+            ('''foo''')
+        "#;
+
+        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Null, true);
+
+        next_type(reader, IonType::Boolean, false);
+        assert_eq!(reader.read_bool()?.unwrap(), true);
+
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 5);
+
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?.unwrap(), 5.0f64);
+
+        next_type(reader, IonType::Decimal, false);
+        assert_eq!(reader.read_decimal()?.unwrap(), Decimal::new(55, -1));
+
+        next_type(reader, IonType::Timestamp, false);
+        assert_eq!(
+            reader.read_timestamp()?.unwrap(),
+            Timestamp::with_ymd(2021, 9, 25).build().unwrap()
+        );
+
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("$ion_1_0"));
+
+        // A mid-stream Ion Version Marker
+        assert_eq!(reader.next()?.unwrap(), RawStreamItem::VersionMarker(1, 0));
+
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("foo"));
+
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?.unwrap(), "hello".to_string());
+
+        // ===== CONTAINERS =====
+
+        // Reading a struct: {foo: bar}
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("bar"));
+        assert_eq!(*reader.field_name().unwrap(), text_token("foo"));
+
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+
+        // Reading a list: ["foo", "bar"]
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?.unwrap(), String::from("foo"));
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?.unwrap(), String::from("bar"));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+
+        // Reading an s-expression: ('''foo''')
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?.unwrap(), String::from("foo"));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+
+        // There are no more top level values.
+        assert_eq!(reader.next()?, None);
+
+        // Asking for more still results in `None`
+        assert_eq!(reader.next()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_multiple_top_level_values_with_comments() -> IonResult<()> {
+        let ion_data = r#"
+            /*
+                Arrokoth is a trans-Neptunian object in the Kuiper belt.
+                It is a contact binary composed of two plenetesimals joined
+                along their major axes.
+            */
+            "(486958) 2014 MU69" // Original designation
+            2014-06-26T // Date of discovery
+            km::36 // width
+        "#;
+
+        let reader = &mut RawTextReader::new(ion_data);
+
+        next_type(reader, IonType::String, false);
+        assert_eq!(
+            reader.read_string()?.unwrap(),
+            String::from("(486958) 2014 MU69")
+        );
+
+        next_type(reader, IonType::Timestamp, false);
+        assert_eq!(
+            reader.read_timestamp()?.unwrap(),
+            Timestamp::with_ymd(2014, 6, 26).build().unwrap()
+        );
+        // TODO: Check for 'km' annotation after change to OwnedSymbolToken
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 36);
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_into_container_field_value() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a:[1,2], b:3}");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.field_name(), Some(&text_token("a")));
+        // The list is only skipped if `next()` is called before stepping in.
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(1));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("b")));
+        assert_eq!(reader.read_i64()?, Some(3));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
     }
 
     #[test]
-    fn test_read_nested_containers() -> IonResult<()> {
+    fn test_text_read_multiple_annotated_top_level_values() -> IonResult<()> {
         let ion_data = r#"
-            {
-                foo: [
-                    1,
-                    [2, 3],
-                    4
-                ],
-                bar: {
-                    a: 5,
-                    b: (true true true)
-                }
-            }
-            11
+            mercury::null
+            venus::'earth'::true
+            $17::mars::5
+            jupiter::5e0
+            'saturn'::5.5
+            $100::$200::$300::2021-09-25T
+            'uranus'::foo
+            neptune::"hello"
+            $55::{foo: $21::bar}
+            pluto::[1, $77::2, 3]
+            haumea::makemake::eris::ceres::(++ -- &&&&&)
         "#;
+        // TODO: Check for annotations after OwnedSymbolToken
+
         let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::Null, true);
+        annotations_eq(reader, &["mercury"]);
+
+        next_type(reader, IonType::Boolean, false);
+        assert_eq!(reader.read_bool()?.unwrap(), true);
+        annotations_eq(reader, &["venus", "earth"]);
+
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 5);
+        annotations_eq(reader, &[local_sid_token(17), text_token("mars")]);
+
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?.unwrap(), 5.0f64);
+        annotations_eq(reader, &["jupiter"]);
+
+        next_type(reader, IonType::Decimal, false);
+        assert_eq!(reader.read_decimal()?.unwrap(), Decimal::new(55, -1));
+        annotations_eq(reader, &["saturn"]);
+
+        next_type(reader, IonType::Timestamp, false);
+        assert_eq!(
+            reader.read_timestamp()?.unwrap(),
+            Timestamp::with_ymd(2021, 9, 25).build().unwrap()
+        );
+        annotations_eq(reader, &[100, 200, 300]);
+
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("foo"));
+        annotations_eq(reader, &["uranus"]);
+
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_string()?.unwrap(), "hello".to_string());
+        annotations_eq(reader, &["neptune"]);
+
+        // ===== CONTAINERS =====
+
+        // Reading a struct: $55::{foo: $21::bar}
         next_type(reader, IonType::Struct, false);
+        annotations_eq(reader, 55);
         reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(*reader.field_name().unwrap(), text_token("foo"));
+        annotations_eq(reader, 21);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("bar"));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+
+        // Reading a list: pluto::[1, $77::2, 3]
         next_type(reader, IonType::List, false);
         reader.step_in()?;
         next_type(reader, IonType::Integer, false);
-        next_type(reader, IonType::List, false);
-        reader.step_in()?;
+        assert_eq!(reader.annotations().len(), 0);
+        assert_eq!(reader.read_i64()?.unwrap(), 1);
         next_type(reader, IonType::Integer, false);
-        // The reader is now at the '2' nested inside of 'foo'
+        annotations_eq(reader, &[77]);
+        assert_eq!(reader.read_i64()?.unwrap(), 2);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.annotations().len(), 0);
+        assert_eq!(reader.read_i64()?.unwrap(), 3);
+        assert_eq!(reader.next()?, None);
         reader.step_out()?;
+
+        // Reading an s-expression: haumea::makemake::eris::ceres::(++ -- &&&&&)
+        next_type(reader, IonType::SExpression, false);
+        annotations_eq(reader, &["haumea", "makemake", "eris", "ceres"]);
+        reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("++"));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("--"));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("&&&&&"));
+        assert_eq!(reader.next()?, None);
         reader.step_out()?;
+
+        // There are no more top level values.
+        assert_eq!(reader.next()?, None);
+
+        // Asking for more still results in `None`
+        assert_eq!(reader.next()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_requires_offset() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("2021-01-01T00:00Z 2021-01-01");
+        next_type(reader, IonType::Timestamp, false);
+        next_type(reader, IonType::Timestamp, false);
+        assert_eq!(reader.next()?, None);
+
+        let assert_missing_offset = |result: IonResult<Option<RawStreamItem>>| match result {
+            Err(IonError::DecodingError { description }) => {
+                assert!(description.contains("no offset"), "{}", description)
+            }
+            other => panic!("expected a decoding error, found {:?}", other),
+        };
+
+        let reader = &mut RawTextReader::new("2021-01-01T00:00");
+        assert_missing_offset(reader.next());
+
+        let reader = &mut RawTextReader::new("[2021-01-01T00:00:00.5]");
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        assert_missing_offset(reader.next());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("5", "5")]
+    #[case("-17", "-17")]
+    #[case("2.5", "2.5")]
+    #[case("0.025", "0.025")]
+    #[case("-0.0", "-0.0")]
+    #[case("25d1", "25d1")]
+    #[case("2.5e0", "2.5e0")]
+    #[case("true", "true")]
+    #[case("2021-01-01T", "2021-01-01T")]
+    #[case("2021-01-01T12:30Z", "2021-01-01T12:30+00:00")]
+    #[case("\"x\"", "x")]
+    #[case("foo", "foo")]
+    #[case("'hello world'", "hello world")]
+    #[case("$10", "$10")]
+    #[case("null.int", "null.int")]
+    #[case("{{aGVsbG8=}}", "{{aGVsbG8=}}")]
+    fn test_read_as_string(#[case] ion_data: &str, #[case] expected: &str) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(ion_data);
+        reader.next()?;
+        assert_eq!(reader.read_as_string()?.as_deref(), Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_as_string_container() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("[1]");
+        assert_eq!(reader.read_as_string()?, None);
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.read_as_string()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_empty_symbol() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("'' ''::5 {'': 1} ('' '')");
+
+        // As a value
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token(""));
+
+        // As an annotation
+        next_type(reader, IonType::Integer, false);
+        annotations_eq(reader, &[""]);
+        assert_eq!(reader.read_i64()?.unwrap(), 5);
+
+        // As a field name
         next_type(reader, IonType::Struct, false);
         reader.step_in()?;
         next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name(), Some(&text_token("")));
+        assert_eq!(reader.read_i64()?.unwrap(), 1);
+        reader.step_out()?;
+
+        // Inside an s-expression
         next_type(reader, IonType::SExpression, false);
         reader.step_in()?;
-        next_type(reader, IonType::Boolean, false);
-        next_type(reader, IonType::Boolean, false);
-        // The reader is now at the second 'true' in the s-expression nested in 'bar'/'b'
-        reader.step_out()?;
-        reader.step_out()?;
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token(""));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token(""));
+        assert_eq!(reader.next()?, None);
         reader.step_out()?;
-        next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 11);
+
+        assert_eq!(reader.next()?, None);
         Ok(())
     }
 
     #[test]
-    fn test_read_container_with_mixed_scalars_and_containers() -> IonResult<()> {
-        let ion_data = r#"
-            {
-                foo: 4,
-                bar: {
-                    a: 5,
-                    b: (true true true)
-                }
+    fn test_read_large_containers() -> IonResult<()> {
+        const NUM_VALUES: i64 = 10_000;
+        let values: Vec<String> = (0..NUM_VALUES).map(|i| i.to_string()).collect();
+        let fields: Vec<String> = (0..NUM_VALUES).map(|i| format!("f{}: {}", i, i)).collect();
+        let ion_data = format!(
+            "[{}] ({}) {{{}}}",
+            values.join(", "),
+            values.join(" "),
+            fields.join(", ")
+        );
+        let reader = &mut RawTextReader::new(ion_data.as_str());
+        for container_type in [IonType::List, IonType::SExpression, IonType::Struct] {
+            next_type(reader, container_type, false);
+            reader.step_in()?;
+            let mut expected = 0;
+            while let Some(item) = reader.next()? {
+                assert_eq!(item, RawStreamItem::Value(IonType::Integer, false));
+                assert_eq!(reader.read_i64()?.unwrap(), expected);
+                expected += 1;
             }
-            42
-        "#;
+            assert_eq!(expected, NUM_VALUES);
+            // Once exhausted, the container keeps reporting that it's at the end.
+            assert_eq!(reader.next()?, None);
+            reader.step_out()?;
+        }
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
 
-        let reader = &mut RawTextReader::new(ion_data);
-        next_type(reader, IonType::Struct, false);
+    #[test]
+    fn test_read_remaining_elements() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("[1, 2, 3] 4");
+        next_type(reader, IonType::List, false);
         reader.step_in()?;
         next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.field_name().unwrap(), &text_token("foo"));
+        assert_eq!(reader.read_i64()?, Some(1));
+        let remaining = reader.read_remaining_elements()?;
+        assert_eq!(
+            remaining,
+            vec![OwnedElement::from(2i64), OwnedElement::from(3i64)]
+        );
+        // The reader is at the end of the list
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_remaining_elements_nested() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(
+            r#"$ion_1_0 foo::{a: [1, (b 2)], 'c': null.int} "d" $ion_1_0 [] $5"#,
+        );
+        let elements = reader.read_remaining_elements()?;
+        let expected = vec![
+            OwnedElement::new_struct(vec![
+                (
+                    "a",
+                    OwnedElement::new_list(vec![
+                        OwnedElement::from(1i64),
+                        OwnedElement::new_sexp(vec![
+                            OwnedElement::new_symbol(owned::text_token("b")),
+                            OwnedElement::from(2i64),
+                        ]),
+                    ]),
+                ),
+                ("c", OwnedElement::new_null(IonType::Integer)),
+            ])
+            .with_annotations(vec![owned::text_token("foo")]),
+            OwnedElement::new_string("d"),
+            OwnedElement::new_list(Vec::new()),
+            OwnedElement::new_symbol(owned::local_sid_token(5)),
+        ];
+        assert_eq!(elements, expected);
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("null.list", IonType::List)]
+    #[case("null.sexp", IonType::SExpression)]
+    #[case("null.struct", IonType::Struct)]
+    fn test_read_typed_null_container(
+        #[case] null_text: &str,
+        #[case] expected_type: IonType,
+    ) -> IonResult<()> {
+        let ion_data = format!("{} [{}] {{a: {}, b: 5}}", null_text, null_text, null_text);
+        let reader = &mut RawTextReader::new(ion_data.as_str());
+
+        // At the top level
+        next_type(reader, expected_type, true);
+        assert!(reader.is_null());
+        assert_eq!(reader.read_null()?, Some(expected_type));
+        assert!(reader.step_in().is_err());
+
+        // In a list
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, expected_type, true);
+        assert!(reader.step_in().is_err());
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+
+        // In a struct, followed by another field
         next_type(reader, IonType::Struct, false);
-        assert_eq!(reader.field_name().unwrap(), &text_token("bar"));
         reader.step_in()?;
+        next_type(reader, expected_type, true);
         next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 5);
+        assert_eq!(reader.read_i64()?, Some(5));
+        assert_eq!(reader.next()?, None);
         reader.step_out()?;
+
         assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_operator_symbol() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("(+ foo '+' && $10 \"+\" 5) '-' bar");
+        next_type(reader, IonType::SExpression, false);
+        assert!(!reader.is_operator_symbol());
+        reader.step_in()?;
+        let mut results = Vec::new();
+        while reader.next()?.is_some() {
+            results.push(reader.is_operator_symbol());
+        }
+        assert_eq!(results, vec![true, false, false, true, false, false, false]);
+        reader.step_out()?;
+        next_type(reader, IonType::Symbol, false);
+        assert!(!reader.is_operator_symbol());
+        next_type(reader, IonType::Symbol, false);
+        assert!(!reader.is_operator_symbol());
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_deeply_nested_container() -> IonResult<()> {
+        const DEPTH: usize = 500;
+        let ion_data = format!("{}1{} 2", "[".repeat(DEPTH), "]".repeat(DEPTH));
+        for validate in [true, false] {
+            let reader = &mut RawTextReaderBuilder::new()
+                .with_validate_skipped_containers(validate)
+                .build(ion_data.as_str());
+            next_type(reader, IonType::List, false);
+            next_type(reader, IonType::Integer, false);
+            assert_eq!(reader.read_i64()?, Some(2));
+            assert_eq!(reader.next()?, None);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_container_with_delimiters_in_text() -> IonResult<()> {
+        let ion_data = r#"
+            {
+                a: "]})\"[",
+                b: '''}''' ''']\'''',
+                'c)': ('(' ']' [')']),
+                d: {{"}}]"}},
+                e: {{ //8= }},
+                // A comment with a ]
+                /* A comment with a }
+                   spanning lines */
+                f: {g: [(), {}, []]},
+            }
+            ["}", 1]
+            2
+        "#;
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_validate_skipped_containers(false)
+            .build(ion_data);
+        next_type(reader, IonType::Struct, false);
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::String, false);
         reader.step_out()?;
         next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 42);
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.next()?, None);
         Ok(())
     }
 
-    #[rstest]
-    #[case(" null ", TextValue::Null(IonType::Null))]
-    #[case(" null.string ", TextValue::Null(IonType::String))]
-    #[case(" true ", TextValue::Boolean(true))]
-    #[case(" false ", TextValue::Boolean(false))]
-    #[case(" 738 ", TextValue::Integer(738))]
-    #[case(" 2.5e0 ", TextValue::Float(2.5))]
-    #[case(" 2.5 ", TextValue::Decimal(Decimal::new(25, -1)))]
-    #[case(" 2007-07-12T ", TextValue::Timestamp(Timestamp::with_ymd(2007, 7, 12).build().unwrap()))]
-    #[case(" foo ", TextValue::Symbol(text_token("foo")))]
-    #[case(" \"hi!\" ", TextValue::String("hi!".to_owned()))]
-    #[case(" {{ZW5jb2RlZA==}} ", TextValue::Blob(Vec::from("encoded".as_bytes())))]
-    #[case(" {{\"hello\"}} ", TextValue::Clob(Vec::from("hello".as_bytes())))]
-    fn test_read_single_top_level_values(#[case] text: &str, #[case] expected_value: TextValue) {
-        let reader = &mut RawTextReader::new(text);
-        next_type(
-            reader,
-            expected_value.ion_type(),
-            matches!(expected_value, TextValue::Null(_)),
-        );
-        // TODO: Redo (or remove?) this test. There's not an API that exposes the
-        //       AnnotatedTextValue any more. We're directly accessing `current_value` as a hack.
-        let actual_value = reader.current_value.clone();
-        assert_eq!(actual_value.unwrap(), expected_value.without_annotations());
+    #[test]
+    fn test_skip_container_nested_in_container() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{a: [1, [2]], b: (3 {c: 4}), d: 5} 6");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::List, false);
+        next_type(reader, IonType::SExpression, false);
+        assert_eq!(reader.field_name(), Some(&text_token("b")));
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        next_type(reader, IonType::Struct, false);
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(5));
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(6));
+        Ok(())
     }
 
     #[test]
-    fn test_text_read_multiple_top_level_values() -> IonResult<()> {
-        let ion_data = r#"
-            null
-            true
-            5
-            5e0
-            5.5
-            2021-09-25T
-            '$ion_1_0' // A quoted symbol, not an IVM
-            $ion_1_0   // An IVM, not a symbol
-            foo
-            "hello"
-            {foo: bar}
-            ["foo", "bar"]
-            ('''foo''')
-        "#;
-
+    fn test_skip_container_validation() -> IonResult<()> {
+        let ion_data = "[1, 2.3.4] 5";
+        // By default, the contents of skipped containers are parsed.
         let reader = &mut RawTextReader::new(ion_data);
-        next_type(reader, IonType::Null, true);
-
-        next_type(reader, IonType::Boolean, false);
-        assert_eq!(reader.read_bool()?.unwrap(), true);
+        next_type(reader, IonType::List, false);
+        assert!(reader.next().is_err());
+        let reader = &mut RawTextReader::new("{a: [(1 2.3.4)]} 5");
+        next_type(reader, IonType::Struct, false);
+        assert!(reader.next().is_err());
 
+        // Scanning skips over them without parsing them.
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_validate_skipped_containers(false)
+            .build(ion_data);
+        next_type(reader, IonType::List, false);
         next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 5);
+        assert_eq!(reader.read_i64()?, Some(5));
 
-        next_type(reader, IonType::Float, false);
-        assert_eq!(reader.read_f64()?.unwrap(), 5.0f64);
-
-        next_type(reader, IonType::Decimal, false);
-        assert_eq!(reader.read_decimal()?.unwrap(), Decimal::new(55, -1));
+        // An unterminated container is an error either way.
+        for validate in [true, false] {
+            let reader = &mut RawTextReaderBuilder::new()
+                .with_validate_skipped_containers(validate)
+                .build("[1, [2, 3]");
+            next_type(reader, IonType::List, false);
+            assert!(reader.next().is_err());
+        }
+        Ok(())
+    }
 
-        next_type(reader, IonType::Timestamp, false);
-        assert_eq!(
-            reader.read_timestamp()?.unwrap(),
-            Timestamp::with_ymd(2021, 9, 25).build().unwrap()
+    #[test]
+    fn test_read_reserved_ion_symbols() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(
+            r#"
+            $ion_foo
+            $ion_shared_symbol_table::5
+            $ion_1_0
+            $ion_1_0_foo
+            $ion_1_0::bar
+            [$ion_1_0]
+            $ion_1_0"#,
         );
-
         next_type(reader, IonType::Symbol, false);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("$ion_1_0"));
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("$ion_foo"));
+
+        next_type(reader, IonType::Integer, false);
+        annotations_eq(reader, &["$ion_shared_symbol_table"]);
 
-        // A mid-stream Ion Version Marker
         assert_eq!(reader.next()?.unwrap(), RawStreamItem::VersionMarker(1, 0));
 
         next_type(reader, IonType::Symbol, false);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("foo"));
-
-        next_type(reader, IonType::String, false);
-        assert_eq!(reader.read_string()?.unwrap(), "hello".to_string());
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("$ion_1_0_foo"));
 
-        // ===== CONTAINERS =====
+        // An annotated `$ion_1_0` is a symbol, not an IVM.
+        next_type(reader, IonType::Symbol, false);
+        annotations_eq(reader, &["$ion_1_0"]);
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("bar"));
 
-        // Reading a struct: {foo: bar}
-        next_type(reader, IonType::Struct, false);
+        // `$ion_1_0` is only an IVM at the top level.
+        next_type(reader, IonType::List, false);
         reader.step_in()?;
         next_type(reader, IonType::Symbol, false);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("bar"));
-        assert_eq!(*reader.field_name().unwrap(), text_token("foo"));
+        assert_eq!(reader.read_symbol()?.unwrap(), text_token("$ion_1_0"));
+        reader.step_out()?;
 
+        // An IVM at the very end of the stream
+        assert_eq!(reader.next()?.unwrap(), RawStreamItem::VersionMarker(1, 0));
         assert_eq!(reader.next()?, None);
-        reader.step_out()?;
+        Ok(())
+    }
 
-        // Reading a list: ["foo", "bar"]
+    #[test]
+    fn test_current_ion_version() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("5 $ion_1_0 6");
+        assert_eq!(reader.current_ion_version(), (1, 0));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.next()?.unwrap(), RawStreamItem::VersionMarker(1, 0));
+        assert_eq!(reader.current_ion_version(), (1, 0));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.current_ion_version(), (1, 0));
+
+        let reader = &mut RawTextReader::new("$ion_1_0 5 $ion_2_0 6");
+        assert_eq!(reader.next()?.unwrap(), RawStreamItem::VersionMarker(1, 0));
+        next_type(reader, IonType::Integer, false);
+        let error = reader.next().unwrap_err();
+        assert!(error.to_string().contains("v2.0"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_i64_annotated() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("kg::5 7 $10::lbs::9 foo::bar");
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.read_i64_annotated()?,
+            Some((vec!["kg".to_string()], 5))
+        );
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64_annotated()?, Some((vec![], 7)));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.read_i64_annotated()?,
+            Some((vec!["$10".to_string(), "lbs".to_string()], 9))
+        );
+        // Not an integer
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_i64_annotated()?, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("[1, 2,]")]
+    #[case("[1, 2 , ]")]
+    #[case("[1, 2,\n]")]
+    #[case("[1, [2],]")]
+    #[case("[1, 2, /* comment */ ]")]
+    fn test_list_trailing_comma(#[case] text: &str) -> IonResult<()> {
+        // Trailing commas are legal Ion and are accepted by default...
+        let reader = &mut RawTextReader::new(text);
         next_type(reader, IonType::List, false);
         reader.step_in()?;
-        next_type(reader, IonType::String, false);
-        assert_eq!(reader.read_string()?.unwrap(), String::from("foo"));
-        next_type(reader, IonType::String, false);
-        assert_eq!(reader.read_string()?.unwrap(), String::from("bar"));
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.next()?.is_some());
         assert_eq!(reader.next()?, None);
         reader.step_out()?;
+        assert_eq!(reader.next()?, None);
 
-        // Reading an s-expression: ('''foo''')
-        next_type(reader, IonType::SExpression, false);
+        // ...but are an error if the reader has been told to forbid them.
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_allow_trailing_commas(false)
+            .build(text);
+        next_type(reader, IonType::List, false);
         reader.step_in()?;
-        next_type(reader, IonType::String, false);
-        assert_eq!(reader.read_string()?.unwrap(), String::from("foo"));
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.next().is_ok());
+        let error = reader.next().unwrap_err();
+        assert!(error.to_string().contains("trailing comma"));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("{a: 1, b: 2,}")]
+    #[case("{a: 1, b: {},\n}")]
+    fn test_struct_trailing_comma(#[case] text: &str) -> IonResult<()> {
+        let reader = &mut RawTextReader::new(text);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.next()?.is_some());
         assert_eq!(reader.next()?, None);
         reader.step_out()?;
-
-        // There are no more top level values.
         assert_eq!(reader.next()?, None);
 
-        // Asking for more still results in `None`
-        assert_eq!(reader.next()?, None);
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_allow_trailing_commas(false)
+            .build(text);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.next().is_ok());
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailing_comma_in_skipped_container() -> IonResult<()> {
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_allow_trailing_commas(false)
+            .build("[1, 2,] 3");
+        next_type(reader, IonType::List, false);
+        assert!(reader.next().is_err());
 
+        // Containers that are skipped without being validated are not checked.
+        let reader = &mut RawTextReaderBuilder::new()
+            .with_allow_trailing_commas(false)
+            .with_validate_skipped_containers(false)
+            .build("[1, 2,] 3");
+        next_type(reader, IonType::List, false);
+        next_type(reader, IonType::Integer, false);
         Ok(())
     }
 
     #[test]
-    fn test_read_multiple_top_level_values_with_comments() -> IonResult<()> {
-        let ion_data = r#"
-            /*
-                Arrokoth is a trans-Neptunian object in the Kuiper belt.
-                It is a contact binary composed of two plenetesimals joined
-                along their major axes.
-            */
-            "(486958) 2014 MU69" // Original designation
-            2014-06-26T // Date of discovery
-            km::36 // width
-        "#;
+    fn test_annotation_spans() -> IonResult<()> {
+        let text = "foo::bar::5 7\n[ 'a b' :: /* comment */ $10 ::true]\n{x: baz\n::null}";
+        let reader = &mut RawTextReader::new(text);
+        let spans_text = |reader: &RawTextReader<&str>| -> Vec<&str> {
+            reader
+                .annotation_spans()
+                .into_iter()
+                .map(|span| &text[span])
+                .collect()
+        };
+        assert!(reader.annotation_spans().is_empty());
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.annotation_spans(), vec![0..3, 5..8]);
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.annotation_spans().is_empty());
 
-        let reader = &mut RawTextReader::new(ion_data);
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Boolean, false);
+        assert_eq!(spans_text(reader), vec!["'a b'", "$10"]);
+        reader.step_out()?;
 
-        next_type(reader, IonType::String, false);
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Null, true);
+        assert_eq!(spans_text(reader), vec!["baz"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_clobs() -> IonResult<()> {
+        let text =
+            "{{\"hello\"}} foo::{{\"\"}} {{\"a\\x62c\"}} [{{'''long''' '''clob'''}}, {{\"x\"}}]";
+        let reader = &mut RawTextReader::new(text);
+        next_type(reader, IonType::Clob, false);
+        // An unescaped clob's bytes are borrowed from the reader's buffer.
+        assert!(matches!(
+            reader.current_value.as_ref().unwrap().value(),
+            TextValue::UnescapedClob(5)
+        ));
         assert_eq!(
-            reader.read_string()?.unwrap(),
-            String::from("(486958) 2014 MU69")
+            reader.read_lob_bytes()?,
+            Some((IonType::Clob, "hello".as_bytes()))
         );
-
-        next_type(reader, IonType::Timestamp, false);
+        assert_eq!(reader.read_clob_bytes()?, Some(b"hello".to_vec()));
+        next_type(reader, IonType::Clob, false);
+        assert_eq!(reader.read_clob_bytes()?, Some(Vec::new()));
+        next_type(reader, IonType::Clob, false);
         assert_eq!(
-            reader.read_timestamp()?.unwrap(),
-            Timestamp::with_ymd(2014, 6, 26).build().unwrap()
+            reader.clob_ref_map(|bytes| bytes.to_vec())?,
+            Some(b"abc".to_vec())
         );
-        // TODO: Check for 'km' annotation after change to OwnedSymbolToken
-        next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 36);
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Clob, false);
+        assert_eq!(reader.read_clob_bytes()?, Some(b"longclob".to_vec()));
+        next_type(reader, IonType::Clob, false);
+        assert_eq!(reader.read_as_string()?, Some("{{\"x\"}}".to_string()));
         Ok(())
     }
 
     #[test]
-    fn test_text_read_multiple_annotated_top_level_values() -> IonResult<()> {
-        let ion_data = r#"
-            mercury::null
-            venus::'earth'::true
-            $17::mars::5
-            jupiter::5e0
-            'saturn'::5.5
-            $100::$200::$300::2021-09-25T
-            'uranus'::foo
-            neptune::"hello"
-            $55::{foo: $21::bar}
-            pluto::[1, $77::2, 3]
-            haumea::makemake::eris::ceres::(++ -- &&&&&)
-        "#;
-        // TODO: Check for annotations after OwnedSymbolToken
-
-        let reader = &mut RawTextReader::new(ion_data);
-        next_type(reader, IonType::Null, true);
-        annotations_eq(reader, &["mercury"]);
-
-        next_type(reader, IonType::Boolean, false);
-        assert_eq!(reader.read_bool()?.unwrap(), true);
-        annotations_eq(reader, &["venus", "earth"]);
-
-        next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.read_i64()?.unwrap(), 5);
-        annotations_eq(reader, &[local_sid_token(17), text_token("mars")]);
+    fn test_max_total_bytes() -> IonResult<()> {
+        // Each line is 4 bytes long; a 10 byte limit allows the reader to load two of them.
+        // The reader needs to look past the end of a value to know that it's complete, so only
+        // the first value can be read before the limit is reached.
+        let text = "foo\n".repeat(1_000);
+        let mut reader = RawTextReaderBuilder::new()
+            .with_max_total_bytes(10)
+            .build(text.as_str());
+        next_type(&mut reader, IonType::Symbol, false);
+        let error = reader.next().unwrap_err();
+        assert!(error.to_string().contains("limit of 10 bytes"));
+        assert!(reader.bytes_read() <= 10);
 
-        next_type(reader, IonType::Float, false);
-        assert_eq!(reader.read_f64()?.unwrap(), 5.0f64);
-        annotations_eq(reader, &["jupiter"]);
+        // A single line with no end is also caught.
+        let text = format!("\"{}", "a".repeat(1_000));
+        let mut reader = RawTextReaderBuilder::new()
+            .with_max_total_bytes(100)
+            .build(text.as_str());
+        assert!(reader.next().is_err());
 
-        next_type(reader, IonType::Decimal, false);
-        assert_eq!(reader.read_decimal()?.unwrap(), Decimal::new(55, -1));
-        annotations_eq(reader, &["saturn"]);
+        // Input that fits within the limit is read normally.
+        let mut reader = RawTextReaderBuilder::new()
+            .with_max_total_bytes(8)
+            .build("foo\nbar\n");
+        next_type(&mut reader, IonType::Symbol, false);
+        next_type(&mut reader, IonType::Symbol, false);
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
 
-        next_type(reader, IonType::Timestamp, false);
-        assert_eq!(
-            reader.read_timestamp()?.unwrap(),
-            Timestamp::with_ymd(2021, 9, 25).build().unwrap()
-        );
-        annotations_eq(reader, &[100, 200, 300]);
+    #[test]
+    fn test_read_symbol_zero() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("$0 $0::5 {$0: 1} ($0)");
 
+        // As a value
         next_type(reader, IonType::Symbol, false);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("foo"));
-        annotations_eq(reader, &["uranus"]);
+        let symbol = reader.read_symbol()?.unwrap();
+        assert_eq!(symbol, local_sid_token(0));
+        assert_eq!(symbol.text(), None);
 
-        next_type(reader, IonType::String, false);
-        assert_eq!(reader.read_string()?.unwrap(), "hello".to_string());
-        annotations_eq(reader, &["neptune"]);
-
-        // ===== CONTAINERS =====
+        // As an annotation
+        next_type(reader, IonType::Integer, false);
+        annotations_eq(reader, &[0]);
+        assert_eq!(reader.annotations()[0].text(), None);
+        assert_eq!(reader.read_i64()?.unwrap(), 5);
 
-        // Reading a struct: $55::{foo: $21::bar}
+        // As a field name
         next_type(reader, IonType::Struct, false);
-        annotations_eq(reader, 55);
-        reader.step_in()?;
-        next_type(reader, IonType::Symbol, false);
-        assert_eq!(*reader.field_name().unwrap(), text_token("foo"));
-        annotations_eq(reader, 21);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("bar"));
-        assert_eq!(reader.next()?, None);
-        reader.step_out()?;
-
-        // Reading a list: pluto::[1, $77::2, 3]
-        next_type(reader, IonType::List, false);
         reader.step_in()?;
         next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.annotations().len(), 0);
+        assert_eq!(reader.field_name(), Some(&local_sid_token(0)));
+        assert_eq!(reader.field_name().unwrap().text(), None);
         assert_eq!(reader.read_i64()?.unwrap(), 1);
-        next_type(reader, IonType::Integer, false);
-        annotations_eq(reader, &[77]);
-        assert_eq!(reader.read_i64()?.unwrap(), 2);
-        next_type(reader, IonType::Integer, false);
-        assert_eq!(reader.annotations().len(), 0);
-        assert_eq!(reader.read_i64()?.unwrap(), 3);
-        assert_eq!(reader.next()?, None);
         reader.step_out()?;
 
-        // Reading an s-expression: haumea::makemake::eris::ceres::(++ -- &&&&&)
+        // Inside an s-expression
         next_type(reader, IonType::SExpression, false);
-        annotations_eq(reader, &["haumea", "makemake", "eris", "ceres"]);
         reader.step_in()?;
         next_type(reader, IonType::Symbol, false);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("++"));
-        next_type(reader, IonType::Symbol, false);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("--"));
-        next_type(reader, IonType::Symbol, false);
-        assert_eq!(reader.read_symbol()?.unwrap(), text_token("&&&&&"));
-        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.read_symbol()?.unwrap(), local_sid_token(0));
         reader.step_out()?;
 
-        // There are no more top level values.
-        assert_eq!(reader.next()?, None);
-
-        // Asking for more still results in `None`
         assert_eq!(reader.next()?, None);
-
         Ok(())
     }
 }
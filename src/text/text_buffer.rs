@@ -1,5 +1,6 @@
 use std::io;
-use std::io::BufRead;
+use std::io::{BufRead, Read};
+use std::ops::Range;
 
 /// A text buffer that pulls more bytes from the input source as needed.
 ///
@@ -20,8 +21,12 @@ pub(crate) struct TextBuffer<R: BufRead> {
     // When the LineBuffer is first constructed and no lines
     // have been read from input, this value is 0.
     line_number: usize,
+    // The number of newlines that have been marked as read via `consume`.
+    lines_consumed: usize,
     // Whether `input` above has reached EOF.
     is_exhausted: bool,
+    // The total number of bytes that have been loaded from `input`.
+    bytes_loaded: usize,
 }
 
 impl<R: BufRead> TextBuffer<R> {
@@ -32,7 +37,9 @@ impl<R: BufRead> TextBuffer<R> {
             line: String::with_capacity(128),
             line_offset: 0,
             line_number: 0,
+            lines_consumed: 0,
             is_exhausted: false,
+            bytes_loaded: 0,
         }
     }
 
@@ -42,6 +49,18 @@ impl<R: BufRead> TextBuffer<R> {
         &self.line[self.line_offset..]
     }
 
+    /// Returns the number of bytes at the beginning of the buffer that have been marked as read
+    /// via the [consume] method. This is reset to zero each time more text is loaded.
+    pub fn offset(&self) -> usize {
+        self.line_offset
+    }
+
+    /// Returns the text in the specified range of the buffer, including text that has already been
+    /// marked as read. Ranges are only valid until more text is loaded into the buffer.
+    pub fn text(&self, range: Range<usize>) -> &str {
+        &self.line[range]
+    }
+
     /// Returns the number of lines that have been loaded from input. The number returned may be:
     /// * Greater than the number of lines that have been marked read via [consume].
     /// * Less than the number of lines that have been requested via [load_next_line] and
@@ -50,6 +69,19 @@ impl<R: BufRead> TextBuffer<R> {
         self.line_number
     }
 
+    /// Returns the number of complete lines (including their trailing newline) that have been marked
+    /// as read via [consume]. Unlike [lines_loaded], this does not include lines that are sitting
+    /// in the buffer waiting to be parsed.
+    pub fn lines_consumed(&self) -> usize {
+        self.lines_consumed
+    }
+
+    /// Returns the total number of bytes that have been loaded from input, including those that
+    /// have already been marked as read via [consume].
+    pub fn bytes_loaded(&self) -> usize {
+        self.bytes_loaded
+    }
+
     /// Returns [true] if the buffer is empty and the end of the input source has been reached;
     /// otherwise, returns false.
     pub fn is_exhausted(&self) -> bool {
@@ -71,9 +103,23 @@ impl<R: BufRead> TextBuffer<R> {
             remaining_line.is_char_boundary(number_of_bytes),
             "Cannot consume() a number of bytes that will leave invalid UTF8 in the current line."
         );
+        self.lines_consumed += remaining_line[..number_of_bytes].matches('\n').count();
         self.line_offset += number_of_bytes;
     }
 
+    /// Returns true if the text remaining in the buffer consists only of whitespace and comments
+    /// (`// rest-of-line` or `/* multiline */`); otherwise, returns false. A rest-of-line comment
+    /// does not need a trailing newline, but an unterminated multiline comment is not blank.
+    pub fn is_blank(&self) -> bool {
+        is_blank_text(self.remaining_text())
+    }
+
+    /// Like [is_blank](Self::is_blank), but only considers the remaining text that follows the
+    /// first `position` bytes.
+    pub fn is_blank_after(&self, position: usize) -> bool {
+        is_blank_text(&self.remaining_text()[position..])
+    }
+
     /// Reads the next line of text from input, appending it to the end of the buffer.
     /// If the input is exhausted (i.e. is at EOF), returns Ok(0).
     pub fn load_next_line(&mut self) -> io::Result<usize> {
@@ -98,9 +144,40 @@ impl<R: BufRead> TextBuffer<R> {
             }
             total_bytes_read += bytes_read;
         }
+        self.bytes_loaded += total_bytes_read;
         Ok(total_bytes_read)
     }
 
+    /// Reads the next line of text from input like [load_next_line], but will not allow the total
+    /// number of bytes loaded from input to exceed [max_bytes_loaded]. If loading the next line
+    /// would exceed that limit, returns Ok(None) without modifying the buffer; at most one byte
+    /// beyond the limit is read from input.
+    pub fn load_next_line_within(&mut self, max_bytes_loaded: usize) -> io::Result<Option<usize>> {
+        self.restack_remaining_text();
+        let allowance = max_bytes_loaded.saturating_sub(self.bytes_loaded);
+        // Request one byte more than the allowance so we can tell whether the line would exceed it.
+        let mut bytes = Vec::new();
+        let bytes_read = (&mut self.input)
+            .take(allowance as u64 + 1)
+            .read_until(b'\n', &mut bytes)?;
+        if bytes_read > allowance {
+            return Ok(None);
+        }
+        if bytes_read == 0 {
+            self.is_exhausted = true;
+            return Ok(Some(0));
+        }
+        let text = std::str::from_utf8(&bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.line.push_str(text);
+        // The last line of `self.input` can have bytes that don't end in '\n'
+        if text.ends_with('\n') {
+            self.line_number += 1;
+        }
+        self.bytes_loaded += bytes_read;
+        Ok(Some(bytes_read))
+    }
+
     /// Provides direct access to the [TextBuffer]'s backing [String]. This can be used to edit the
     /// input text before it is processed. Note that lines added or removed when using this method
     /// will not be reflected in subsequent calls to [lines_loaded].
@@ -108,6 +185,19 @@ impl<R: BufRead> TextBuffer<R> {
         &mut self.line
     }
 
+    /// Consumes the [TextBuffer], returning the input source. Any text that has been loaded into
+    /// the buffer but not yet marked as read via [consume] is discarded.
+    pub fn into_input(self) -> R {
+        self.input
+    }
+
+    /// Returns a mutable reference to the input source. Reading from the source directly will
+    /// skip over text that the buffer would otherwise have loaded.
+    #[cfg(feature = "async")]
+    pub(crate) fn input_mut(&mut self) -> &mut R {
+        &mut self.input
+    }
+
     /// Removes the part of the current line that has already been consumed and moves any remaining
     /// text back to the beginning of the buffer.
     fn restack_remaining_text(&mut self) {
@@ -129,9 +219,30 @@ impl<R: BufRead> TextBuffer<R> {
     }
 }
 
+// Returns true if `text` consists only of whitespace and comments. See [TextBuffer::is_blank].
+fn is_blank_text(mut text: &str) -> bool {
+    loop {
+        text = text.trim_start_matches(&[' ', '\t', '\n', '\r', '\x0B', '\x0C'][..]);
+        if let Some(comment) = text.strip_prefix("//") {
+            text = match comment.find('\n') {
+                Some(index) => &comment[index..],
+                None => "",
+            };
+        } else if let Some(comment) = text.strip_prefix("/*") {
+            text = match comment.find("*/") {
+                Some(index) => &comment[index + 2..],
+                None => return false,
+            };
+        } else {
+            return text.is_empty();
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod text_buffer_tests {
     use super::*;
+    use rstest::*;
     use std::io;
 
     fn text_buffer(text: &str) -> TextBuffer<io::Cursor<&str>> {
@@ -171,6 +282,22 @@ pub(crate) mod text_buffer_tests {
         assert_eq!(buffer.remaining_text(), "");
     }
 
+    #[rstest]
+    #[case("", true)]
+    #[case("   // x\n", true)]
+    #[case("\t/* x */ \r\n// y", true)]
+    #[case("/* a */// b\n/* c\n d */\n", true)]
+    #[case("  5", false)]
+    #[case("// x\n5", false)]
+    #[case("/* x */5", false)]
+    #[case("/* unterminated", false)]
+    #[case("/ x", false)]
+    fn test_is_blank(#[case] text: &str, #[case] expected: bool) {
+        let mut buffer = text_buffer(text);
+        while buffer.load_next_line().unwrap() > 0 {}
+        assert_eq!(buffer.is_blank(), expected);
+    }
+
     #[test]
     fn test_consume() {
         let mut buffer = text_buffer("foo bar baz quux");
@@ -184,6 +311,32 @@ pub(crate) mod text_buffer_tests {
         assert_eq!(buffer.remaining_text(), "x");
     }
 
+    #[test]
+    fn test_lines_consumed() {
+        let mut buffer = text_buffer("foo\nbar\nbaz\n");
+        buffer.load_next_n_lines(3).unwrap();
+        assert_eq!(buffer.lines_loaded(), 3);
+        assert_eq!(buffer.lines_consumed(), 0);
+        buffer.consume(3);
+        assert_eq!(buffer.lines_consumed(), 0);
+        buffer.consume(1);
+        assert_eq!(buffer.lines_consumed(), 1);
+        buffer.consume(6);
+        assert_eq!(buffer.lines_consumed(), 2);
+        assert_eq!(buffer.remaining_text(), "z\n");
+    }
+
+    #[test]
+    fn test_load_next_line_within() {
+        let mut buffer = text_buffer("foo\nbar\nbaz\n");
+        assert_eq!(buffer.load_next_line_within(8).unwrap(), Some(4));
+        assert_eq!(buffer.load_next_line_within(8).unwrap(), Some(4));
+        assert_eq!(buffer.bytes_loaded(), 8);
+        assert_eq!(buffer.load_next_line_within(8).unwrap(), None);
+        assert_eq!(buffer.remaining_text(), "foo\nbar\n");
+        assert_eq!(buffer.lines_loaded(), 2);
+    }
+
     #[test]
     #[should_panic]
     fn test_consume_err_illegal_utf8_offset() {
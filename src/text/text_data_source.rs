@@ -1,5 +1,7 @@
 use std::io;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, Read};
+
+use crate::result::{IonError, IonResult};
 
 /// Types that implement this trait can be converted into an implementation of [io::BufRead],
 /// allowing them to be passed to the [TextReader::new] constructor. This allows [TextReader::new]
@@ -40,3 +42,69 @@ impl<T: io::Read> TextIonDataSource for BufReader<T> {
         self
     }
 }
+
+/// A text source that pulls lines of Ion text from an iterator (for example, one fed by a
+/// channel). Each line is followed by a newline when it is read; lines should not include their
+/// own line terminators. If the iterator yields an `Err`, it is surfaced as an IO error by the
+/// read operation that requested more text.
+pub struct LineIteratorSource<I> {
+    lines: I,
+    // The line currently being read, including its trailing newline.
+    current_line: String,
+    // How much of `current_line` has been consumed.
+    position: usize,
+}
+
+impl<I: Iterator<Item = IonResult<String>>> LineIteratorSource<I> {
+    pub fn new(lines: I) -> LineIteratorSource<I> {
+        LineIteratorSource {
+            lines,
+            current_line: String::new(),
+            position: 0,
+        }
+    }
+
+    // Returns the iterator that this source pulls its lines from.
+    #[cfg(feature = "async")]
+    pub(crate) fn lines_mut(&mut self) -> &mut I {
+        &mut self.lines
+    }
+}
+
+impl<I: Iterator<Item = IonResult<String>>> Read for LineIteratorSource<I> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = self.fill_buf()?.read(buf)?;
+        self.consume(bytes_read);
+        Ok(bytes_read)
+    }
+}
+
+impl<I: Iterator<Item = IonResult<String>>> BufRead for LineIteratorSource<I> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.position >= self.current_line.len() {
+            match self.lines.next() {
+                None => return Ok(&[]),
+                Some(Ok(line)) => {
+                    self.current_line = line;
+                    self.current_line.push('\n');
+                    self.position = 0;
+                }
+                Some(Err(IonError::IoError { source })) => return Err(source),
+                Some(Err(e)) => return Err(io::Error::new(io::ErrorKind::Other, e)),
+            }
+        }
+        Ok(&self.current_line.as_bytes()[self.position..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position = (self.position + amount).min(self.current_line.len());
+    }
+}
+
+impl<I: Iterator<Item = IonResult<String>>> TextIonDataSource for LineIteratorSource<I> {
+    type TextSource = Self;
+
+    fn to_text_ion_data_source(self) -> Self::TextSource {
+        self
+    }
+}
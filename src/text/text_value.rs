@@ -26,6 +26,10 @@ impl AnnotatedTextValue {
     pub(crate) fn annotations(&self) -> &[RawSymbolToken] {
         &self.annotations
     }
+
+    pub(crate) fn into_parts(self) -> (Vec<RawSymbolToken>, TextValue) {
+        (self.annotations, self.value)
+    }
 }
 
 impl PartialEq<TextValue> for AnnotatedTextValue {
@@ -53,6 +57,10 @@ pub(crate) enum TextValue {
     // TODO: [BC]lob(&[u8]) will be possible if/when we add reusable buffers to the TextReader.
     Blob(Vec<u8>),
     Clob(Vec<u8>),
+    // A short clob (e.g. `{{"hello"}}`) that contains no escape sequences. Its bytes are the text
+    // of its body in the input, which is this many bytes long and immediately follows the opening
+    // `{{"`. The reader can borrow them directly from its buffer instead of allocating a copy.
+    UnescapedClob(usize),
     ListStart,
     SExpressionStart,
     StructStart,
@@ -71,7 +79,7 @@ impl TextValue {
             TextValue::String(_) => IonType::String,
             TextValue::Symbol(_) => IonType::Symbol,
             TextValue::Blob(_) => IonType::Blob,
-            TextValue::Clob(_) => IonType::Clob,
+            TextValue::Clob(_) | TextValue::UnescapedClob(_) => IonType::Clob,
             TextValue::ListStart => IonType::List,
             TextValue::SExpressionStart => IonType::SExpression,
             TextValue::StructStart => IonType::Struct,
@@ -1,8 +1,13 @@
+use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::{illegal_operation, IonResult};
+use crate::types::coefficient::Sign;
+use crate::types::decimal::Decimal;
 use crate::types::timestamp::{Precision, Timestamp};
 use crate::IonType;
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, TimeZone, Timelike};
+use num_bigint::{BigInt, BigUint};
+use std::borrow::Cow;
 use std::convert::TryInto;
 use std::io::{BufWriter, Write};
 use std::mem;
@@ -12,9 +17,15 @@ pub struct TextWriter<W: Write> {
     annotations: Vec<String>,
     field_name: Option<String>,
     containers: Vec<IonType>,
+    // For each open container, whether any values have been written to it yet.
+    container_is_empty: Vec<bool>,
     string_escape_codes: Vec<String>,
+    pretty_print: bool,
 }
 
+// The text used to indent each level of nesting when pretty printing.
+const PRETTY_INDENTATION: &str = "  ";
+
 /**
  * String escape codes, for Ion Clob.
  */
@@ -51,6 +62,110 @@ fn string_escape_code_init() -> Vec<String> {
     string_escape_codes
 }
 
+thread_local! {
+    // The escape codes used when writing quoted symbols outside of a TextWriter.
+    static STRING_ESCAPE_CODES: Vec<String> = string_escape_code_init();
+}
+
+/// Returns `text` unchanged if it can be written as an unquoted Ion symbol. Otherwise, returns
+/// a copy of `text` wrapped in single quotes with any special characters escaped.
+///
+/// A symbol can be left unquoted if it is a valid identifier that is not a keyword (`null`,
+/// `true`, `false`, `nan`) and does not look like a symbol ID (`$10`) or an Ion version marker
+/// (`$ion_1_0`). Operator symbols like `+` are always quoted because they are only legal unquoted
+/// inside an s-expression.
+pub fn quote_symbol_if_needed(text: &str) -> Cow<'_, str> {
+    if is_unquoted_identifier(text) {
+        return Cow::Borrowed(text);
+    }
+    let mut quoted = String::with_capacity(text.len() + 2);
+    STRING_ESCAPE_CODES.with(|escape_codes| push_quoted(&mut quoted, text, '\'', escape_codes));
+    Cow::Owned(quoted)
+}
+
+// Like [quote_symbol_if_needed], but quotes `text` even if it is a valid identifier.
+fn quote_symbol(text: &str) -> Cow<'_, str> {
+    match quote_symbol_if_needed(text) {
+        Cow::Borrowed(text) => Cow::Owned(format!("'{}'", text)),
+        quoted => quoted,
+    }
+}
+
+// Renders a raw symbol token the way the writer writes annotations and symbol values. Text is
+// always quoted, so `$10` is only read back as a symbol ID if the token was one.
+fn symbol_token_text(token: &RawSymbolToken) -> Cow<'_, str> {
+    match token {
+        RawSymbolToken::Text(text) => quote_symbol(text),
+        RawSymbolToken::SymbolId(sid) => Cow::Owned(format!("${}", sid)),
+    }
+}
+
+// Appends `text` to `output` wrapped in `quote`s, escaping `quote` and any ASCII character that
+// has an entry in `escape_codes`.
+fn push_quoted(output: &mut String, text: &str, quote: char, escape_codes: &[String]) {
+    output.push(quote);
+    for c in text.chars() {
+        match c {
+            c if c == quote => {
+                output.push('\\');
+                output.push(c);
+            }
+            // Non-ASCII characters are written as-is; the table's entries for bytes above 0x7F
+            // are only meaningful in clobs.
+            c if c.is_ascii() && !escape_codes[c as usize].is_empty() => {
+                output.push_str(&escape_codes[c as usize])
+            }
+            c => output.push(c),
+        }
+    }
+    output.push(quote);
+}
+
+// Returns true if `text` would be read back as the same symbol when written without quotes.
+fn is_unquoted_identifier(text: &str) -> bool {
+    const KEYWORDS: &[&str] = &["null", "true", "false", "nan"];
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c == '$' || c == '_' || c.is_ascii_alphabetic() => {}
+        _ => return false,
+    }
+    if !chars.all(|c| c == '$' || c == '_' || c.is_ascii_alphanumeric()) {
+        return false;
+    }
+    let is_symbol_id =
+        text.starts_with('$') && text.len() > 1 && text[1..].bytes().all(|b| b.is_ascii_digit());
+    // At the top level, an identifier like `$ion_1_0` is read as an Ion version marker rather
+    // than as a symbol.
+    let is_digits = |text: &str| !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit());
+    let is_version_marker = text
+        .strip_prefix("$ion_")
+        .and_then(|version| version.split_once('_'))
+        .map(|(major, minor)| is_digits(major) && is_digits(minor))
+        .unwrap_or(false);
+    !is_symbol_id && !is_version_marker && !KEYWORDS.contains(&text)
+}
+
+// Renders a Decimal in its Ion text form. Decimals with a negative exponent are written using a
+// decimal point (`2.50`); all others are written with an explicit exponent (`25d1`) so that they
+// cannot be mistaken for integers.
+pub(crate) fn decimal_text(decimal: &Decimal) -> String {
+    let sign = match decimal.coefficient.sign() {
+        Sign::Negative => "-",
+        Sign::Positive => "",
+    };
+    let digits = BigUint::from(decimal.coefficient.magnitude().clone()).to_string();
+    if decimal.exponent >= 0 {
+        return format!("{}{}d{}", sign, digits, decimal.exponent);
+    }
+    let scale = decimal.exponent.unsigned_abs() as usize;
+    if digits.len() > scale {
+        let (whole, fractional) = digits.split_at(digits.len() - scale);
+        format!("{}{}.{}", sign, whole, fractional)
+    } else {
+        format!("{}0.{:0>width$}", sign, digits, width = scale)
+    }
+}
+
 impl<W: Write> TextWriter<W> {
     /// Constructs a new instance of TextWriter that writes values to the provided io::Write
     /// implementation.
@@ -60,10 +175,20 @@ impl<W: Write> TextWriter<W> {
             annotations: vec![],
             field_name: None,
             containers: vec![],
+            container_is_empty: vec![],
             string_escape_codes: string_escape_code_init(),
+            pretty_print: false,
         }
     }
 
+    /// If set to `true`, the writer will place each value inside a container on its own line,
+    /// indented to reflect its depth, and will put a space between each field name and its value.
+    /// Defaults to `false`, which writes each top-level value on a single line.
+    pub fn with_pretty_print(mut self, pretty_print: bool) -> TextWriter<W> {
+        self.pretty_print = pretty_print;
+        self
+    }
+
     /// Returns a reference to the underlying io::Write implementation.
     pub fn output(&self) -> &W {
         self.output.get_ref()
@@ -84,7 +209,8 @@ impl<W: Write> TextWriter<W> {
 
     /// Sets the current field name to `name`. If the TextWriter is currently positioned inside
     /// of a struct, the field name will be written before the next value. Otherwise, it will be
-    /// ignored.
+    /// ignored. `name` is written as-is; use [quote_symbol_if_needed] to quote it if it is not
+    /// a valid identifier.
     pub fn set_field_name(&mut self, name: &str) {
         self.field_name = Some(name.to_string());
     }
@@ -92,7 +218,17 @@ impl<W: Write> TextWriter<W> {
     /// Sets a list of annotations that will be applied to the next value that is written.
     pub fn set_annotations(&mut self, annotations: &[&str]) {
         self.annotations
-            .extend(annotations.iter().map(|s| s.to_string()));
+            .extend(annotations.iter().map(|s| quote_symbol(s).into_owned()));
+    }
+
+    /// Like [set_annotations](Self::set_annotations), but accepts raw symbol tokens. Symbol IDs
+    /// are written unquoted (`$10`) so they will be read back as symbol IDs.
+    pub fn set_annotation_tokens(&mut self, annotations: &[RawSymbolToken]) {
+        self.annotations.extend(
+            annotations
+                .iter()
+                .map(|token| symbol_token_text(token).into_owned()),
+        );
     }
 
     /// Begins a container (List, S-Expression, or Struct). If `ion_type` is not a container type,
@@ -107,6 +243,7 @@ impl<W: Write> TextWriter<W> {
             _ => return illegal_operation(format!("Cannot step into a(n) {:?}", ion_type)),
         }
         self.containers.push(ion_type);
+        self.container_is_empty.push(true);
         Ok(())
     }
 
@@ -129,6 +266,11 @@ impl<W: Write> TextWriter<W> {
             Some(scalar) => unreachable!("Inside a non-container type: {:?}", scalar),
             None => return illegal_operation("Cannot step out of the top level."),
         };
+        let was_empty = self.container_is_empty.pop().unwrap_or(true);
+        if self.pretty_print && !was_empty {
+            // Put the closing delimiter on its own line, aligned with the opening delimiter.
+            self.write_newline_and_indentation()?;
+        }
         write!(self.output, "{}", end_delimiter)?;
         self.write_value_delimiter()?;
         Ok(())
@@ -137,6 +279,11 @@ impl<W: Write> TextWriter<W> {
     // Called after each value is written to emit an appropriate delimiter before the next value.
     fn write_value_delimiter(&mut self) -> IonResult<()> {
         use IonType::*;
+        if self.pretty_print && !self.containers.is_empty() {
+            // When pretty printing, delimiters are written before the next value (if any) by
+            // `write_value_metadata` so that the last value in a container has no trailing comma.
+            return Ok(());
+        }
         let delimiter = match self.containers.last() {
             Some(Struct) | Some(List) => ",",
             Some(SExpression) => " ",
@@ -147,16 +294,45 @@ impl<W: Write> TextWriter<W> {
         Ok(())
     }
 
+    // Writes a newline followed by the indentation for the current depth.
+    fn write_newline_and_indentation(&mut self) -> IonResult<()> {
+        writeln!(self.output)?;
+        for _ in 0..self.containers.len() {
+            write!(self.output, "{}", PRETTY_INDENTATION)?;
+        }
+        Ok(())
+    }
+
+    // When pretty printing, writes the delimiter that separates this value from the previous
+    // value in the same container (if any) and moves to a new, indented line.
+    fn write_pretty_value_prefix(&mut self) -> IonResult<()> {
+        let is_first_value = match self.container_is_empty.last_mut() {
+            Some(is_empty) => std::mem::replace(is_empty, false),
+            // Top-level values are already followed by a newline.
+            None => return Ok(()),
+        };
+        if !is_first_value && self.containers.last() != Some(&IonType::SExpression) {
+            write!(self.output, ",")?;
+        }
+        self.write_newline_and_indentation()
+    }
+
     // Write the field name and annotations if set
     fn write_value_metadata(&mut self) -> IonResult<()> {
+        if self.pretty_print {
+            self.write_pretty_value_prefix()?;
+        } else if let Some(is_empty) = self.container_is_empty.last_mut() {
+            *is_empty = false;
+        }
         if let Some(field_name) = &self.field_name.take() {
-            write!(self.output, "{}:", field_name)?;
+            let separator = if self.pretty_print { ": " } else { ":" };
+            write!(self.output, "{}{}", field_name, separator)?;
         } else if self.is_in_struct() {
             return illegal_operation("Values inside a struct must have a field name.".to_string());
         }
         if !self.annotations.is_empty() {
             for annotation in &self.annotations {
-                write!(self.output, "'{}'::", annotation)?;
+                write!(self.output, "{}::", annotation)?;
             }
             self.annotations.clear();
         }
@@ -222,6 +398,14 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
+    /// Writes the provided BigInt value as an Ion integer.
+    pub fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{}", value)?;
+            Ok(())
+        })
+    }
+
     /// Writes the provided f64 value as an Ion float.
     pub fn write_f64(&mut self, value: f64) -> IonResult<()> {
         self.write_scalar(|output| {
@@ -260,6 +444,15 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
+    /// Writes the provided Decimal as an Ion decimal. Unlike [write_big_decimal], this preserves
+    /// negative zero.
+    pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{}", decimal_text(value))?;
+            Ok(())
+        })
+    }
+
     // Helper method for [write_timestamp]. Writes the timestamp to output using +/-HH:MM format.
     fn write_offset(output: &mut BufWriter<W>, offset_minutes: Option<i32>) -> IonResult<()> {
         if offset_minutes.is_none() {
@@ -361,15 +554,26 @@ impl<W: Write> TextWriter<W> {
     /// Writes the provided &str value as an Ion symbol.
     pub fn write_symbol<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
         self.write_scalar(|output| {
-            write!(output, "'{}'", value.as_ref())?;
+            write!(output, "{}", quote_symbol(value.as_ref()))?;
+            Ok(())
+        })
+    }
+
+    /// Writes the provided raw symbol token as an Ion symbol. Text is quoted, while symbol IDs are
+    /// written unquoted (`$10`) so they will be read back as symbol IDs.
+    pub fn write_symbol_token(&mut self, token: &RawSymbolToken) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{}", symbol_token_text(token))?;
             Ok(())
         })
     }
 
     /// Writes the provided &str value as an Ion string.
     pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let mut quoted = String::with_capacity(value.as_ref().len() + 2);
+        push_quoted(&mut quoted, value.as_ref(), '"', &self.string_escape_codes);
         self.write_scalar(|output| {
-            write!(output, "\"{}\"", value.as_ref())?;
+            write!(output, "{}", quoted)?;
             Ok(())
         })
     }
@@ -416,12 +620,15 @@ impl<W: Write> TextWriter<W> {
 
 #[cfg(test)]
 mod tests {
+    use crate::raw_symbol_token::RawSymbolToken;
     use crate::result::IonResult;
-    use crate::text::writer::TextWriter;
+    use crate::text::writer::{quote_symbol_if_needed, TextWriter};
+    use crate::types::decimal::Decimal;
     use crate::types::timestamp::Timestamp;
     use crate::IonType;
     use bigdecimal::BigDecimal;
     use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
     use std::str;
     use std::str::FromStr;
 
@@ -436,6 +643,42 @@ mod tests {
         assert_eq!(str::from_utf8(&output).unwrap(), expected);
     }
 
+    fn pretty_writer_test<F>(mut commands: F, expected: &str)
+    where
+        F: FnMut(&mut TextWriter<&mut Vec<u8>>) -> IonResult<()>,
+    {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output).with_pretty_print(true);
+        commands(&mut writer).expect("Invalid TextWriter test commands.");
+        drop(writer);
+        assert_eq!(str::from_utf8(&output).unwrap(), expected);
+    }
+
+    #[test]
+    fn write_pretty_containers() {
+        pretty_writer_test(
+            |w| {
+                w.step_in(IonType::Struct)?;
+                w.set_field_name("a");
+                w.step_in(IonType::List)?;
+                w.write_i64(1)?;
+                w.write_i64(2)?;
+                w.step_out()?;
+                w.set_field_name("b");
+                w.step_in(IonType::SExpression)?;
+                w.write_i64(3)?;
+                w.write_i64(4)?;
+                w.step_out()?;
+                w.set_field_name("c");
+                w.step_in(IonType::List)?;
+                w.step_out()?;
+                w.step_out()?;
+                w.write_i64(5)
+            },
+            "{\n  a: [\n    1,\n    2\n  ],\n  b: (\n    3\n    4\n  ),\n  c: []\n}\n5\n",
+        );
+    }
+
     #[test]
     fn write_null_null() {
         writer_test(|w| w.write_null(IonType::Null), "null\n");
@@ -461,6 +704,12 @@ mod tests {
         writer_test(|w| w.write_i64(7), "7\n");
     }
 
+    #[test]
+    fn write_big_int() {
+        let value: BigInt = "-99999999999999999999".parse().unwrap();
+        writer_test(|w| w.write_big_int(&value), "-99999999999999999999\n");
+    }
+
     #[test]
     fn write_f64() {
         writer_test(|w| w.write_f64(700f64), "7e2\n");
@@ -477,6 +726,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_symbol_tokens() {
+        writer_test(
+            |w| {
+                w.set_annotation_tokens(&[
+                    RawSymbolToken::SymbolId(10),
+                    RawSymbolToken::Text("$11".to_string()),
+                ]);
+                w.write_symbol_token(&RawSymbolToken::SymbolId(12))?;
+                w.write_symbol_token(&RawSymbolToken::Text("$13".to_string()))
+            },
+            "$10::'$11'::$12\n'$13'\n",
+        );
+    }
+
     #[test]
     fn write_decimal() {
         let decimal_text = "731221.9948";
@@ -486,6 +750,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_negative_zero_decimal() {
+        writer_test(
+            |w| w.write_decimal(&Decimal::negative_zero_with_exponent(-1)),
+            "-0.0\n",
+        );
+    }
+
     #[test]
     fn write_datetime_epoch() {
         #![allow(deprecated)] // `write_datetime` is deprecated
@@ -652,4 +924,63 @@ mod tests {
             "{a:\"foo\",b:21,c:'qux'::'bar',}\n",
         );
     }
+
+    #[test]
+    fn quote_symbol_identifier() {
+        assert_eq!(quote_symbol_if_needed("foo"), "foo");
+        assert_eq!(quote_symbol_if_needed("$foo_1"), "$foo_1");
+    }
+
+    #[test]
+    fn quote_symbol_empty() {
+        assert_eq!(quote_symbol_if_needed(""), "''");
+    }
+
+    #[test]
+    fn quote_symbol_keyword() {
+        assert_eq!(quote_symbol_if_needed("true"), "'true'");
+        assert_eq!(quote_symbol_if_needed("null"), "'null'");
+        assert_eq!(quote_symbol_if_needed("nan"), "'nan'");
+    }
+
+    #[test]
+    fn quote_symbol_with_space() {
+        assert_eq!(quote_symbol_if_needed("a b"), "'a b'");
+    }
+
+    #[test]
+    fn quote_symbol_leading_digit() {
+        assert_eq!(quote_symbol_if_needed("123abc"), "'123abc'");
+    }
+
+    #[test]
+    fn quote_symbol_symbol_id() {
+        assert_eq!(quote_symbol_if_needed("$10"), "'$10'");
+    }
+
+    #[test]
+    fn quote_symbol_version_marker() {
+        assert_eq!(quote_symbol_if_needed("$ion_1_0"), "'$ion_1_0'");
+        assert_eq!(quote_symbol_if_needed("$ion_2_13"), "'$ion_2_13'");
+        assert_eq!(quote_symbol_if_needed("$ion_1_00"), "'$ion_1_00'");
+        assert_eq!(quote_symbol_if_needed("$ion_1_0_foo"), "$ion_1_0_foo");
+        assert_eq!(quote_symbol_if_needed("$ion_1"), "$ion_1");
+        assert_eq!(
+            quote_symbol_if_needed("$ion_symbol_table"),
+            "$ion_symbol_table"
+        );
+    }
+
+    #[test]
+    fn quote_symbol_escapes() {
+        assert_eq!(quote_symbol_if_needed("it's"), "'it\\'s'");
+        assert_eq!(quote_symbol_if_needed("a\\b"), "'a\\\\b'");
+        assert_eq!(quote_symbol_if_needed("+"), "'+'");
+        assert_eq!(quote_symbol_if_needed("a\nb\tc"), "'a\\nb\\tc'");
+        assert_eq!(
+            quote_symbol_if_needed("a\u{0}\u{1}\u{7f}"),
+            "'a\\0\\x01\\x7f'"
+        );
+        assert_eq!(quote_symbol_if_needed("caf\u{e9}"), "'caf\u{e9}'");
+    }
 }
@@ -45,6 +45,14 @@ impl Coefficient {
         }
     }
 
+    pub(crate) fn is_zero(&self) -> bool {
+        match &self.magnitude {
+            Magnitude::U64(0) => true,
+            Magnitude::BigUInt(b) => b.is_zero(),
+            _ => false,
+        }
+    }
+
     pub(crate) fn is_negative_zero(&self) -> bool {
         match (self.sign, &self.magnitude) {
             (Sign::Negative, Magnitude::U64(0)) => true,
@@ -2,12 +2,18 @@ use std::cmp::Ordering;
 
 use bigdecimal::{BigDecimal, Signed};
 use num_bigint::{BigInt, BigUint, ToBigUint};
+use num_traits::Zero;
 
-use crate::result::{illegal_operation, IonError};
+use crate::result::{illegal_operation, IonError, IonResult};
 use crate::types::coefficient::{Coefficient, Sign};
 use crate::types::magnitude::Magnitude;
 use std::convert::{TryFrom, TryInto};
 
+/// The largest number of zeros that [Decimal::round] will append to a coefficient. Each zero
+/// grows the coefficient by a factor of ten, so this keeps a large `scale` from allocating
+/// an arbitrarily large coefficient.
+const MAX_ROUNDING_PADDING: i64 = 10_000;
+
 /// An arbitrary-precision Decimal type with a distinct representation of negative zero (`-0`).
 #[derive(Clone, Debug)]
 pub struct Decimal {
@@ -16,6 +22,25 @@ pub struct Decimal {
     pub(crate) exponent: i64,
 }
 
+/// Strategies for discarding digits when rounding a [Decimal] with [Decimal::round].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round away from zero.
+    Up,
+    /// Round towards zero (truncate).
+    Down,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards the nearest neighbor; ties are rounded away from zero.
+    HalfUp,
+    /// Round towards the nearest neighbor; ties are rounded towards zero.
+    HalfDown,
+    /// Round towards the nearest neighbor; ties are rounded towards the even neighbor.
+    HalfEven,
+}
+
 impl Decimal {
     /// Constructs a new Decimal with the provided components. The value of the decimal is
     ///    (coefficient * 10^exponent) * (if sign == Sign::Negative { -1 } else { 1 })
@@ -44,6 +69,179 @@ impl Decimal {
         }
     }
 
+    /// Returns a Decimal with the same value as this one, but with any trailing zeros removed from
+    /// its coefficient and its exponent adjusted to compensate. For example, `1.200`, `120d-2`, and
+    /// `0.0120d2` are all normalized to `12d-1`. Zeros are normalized to `0d0` (or `-0d0`).
+    ///
+    /// Note that normalizing a Decimal can change its precision. Ion does not consider decimals
+    /// with different precisions to be equivalent; `1.200` and `1.2` are distinct Ion values.
+    pub fn normalized(&self) -> Decimal {
+        let sign = self.coefficient.sign();
+        let mut exponent = self.exponent;
+        let magnitude = match self.coefficient.magnitude() {
+            Magnitude::U64(0) => return Decimal::new(Coefficient::new(sign, 0u64), 0),
+            Magnitude::U64(magnitude) => {
+                let mut magnitude = *magnitude;
+                while magnitude % 10 == 0 {
+                    magnitude /= 10;
+                    exponent += 1;
+                }
+                Magnitude::U64(magnitude)
+            }
+            Magnitude::BigUInt(magnitude) if magnitude.is_zero() => {
+                return Decimal::new(Coefficient::new(sign, 0u64), 0)
+            }
+            Magnitude::BigUInt(magnitude) => {
+                let ten = BigUint::from(10u32);
+                let mut magnitude = magnitude.clone();
+                while (&magnitude % &ten).is_zero() {
+                    magnitude /= &ten;
+                    exponent += 1;
+                }
+                // Prefer the compact representation if the trimmed magnitude now fits in a u64.
+                Magnitude::from(magnitude)
+            }
+        };
+        Decimal::new(Coefficient::new(sign, magnitude), exponent)
+    }
+
+    /// Returns a Decimal with exactly `scale` digits after the decimal point (that is, with an
+    /// exponent of `-scale`), using `mode` to decide how discarded digits affect the result. For
+    /// example, rounding `2.555` to a scale of `2` produces `2.56` with [RoundingMode::HalfUp].
+    /// If this Decimal has fewer than `scale` fractional digits, its coefficient is padded with
+    /// zeros. The sign of the Decimal is always preserved, so rounding `-0.001` to a scale of
+    /// `2` produces `-0.00`.
+    ///
+    /// Returns an error if `scale` is `i64::MIN` (whose exponent can't be represented) or if
+    /// reaching it would require padding the coefficient with more than 10,000 zeros.
+    pub fn round(&self, scale: i64, mode: RoundingMode) -> IonResult<Decimal> {
+        let sign = self.coefficient.sign();
+        let exponent = match scale.checked_neg() {
+            Some(exponent) => exponent,
+            None => {
+                return illegal_operation(format!(
+                    "Cannot round a Decimal to a scale of {}.",
+                    scale
+                ))
+            }
+        };
+        let magnitude: BigUint = self.coefficient.magnitude().to_biguint().unwrap();
+        if self.exponent >= exponent {
+            // No digits need to be discarded; scale up the coefficient to match the new exponent.
+            let padding = match self
+                .exponent
+                .checked_sub(exponent)
+                .filter(|padding| *padding <= MAX_ROUNDING_PADDING)
+            {
+                Some(padding) => padding as u32,
+                None => {
+                    return illegal_operation(format!(
+                        "Cannot round a Decimal with an exponent of {} to a scale of {}; the \
+                        coefficient would need more than {} zeros of padding.",
+                        self.exponent, scale, MAX_ROUNDING_PADDING
+                    ))
+                }
+            };
+            let padding = BigUint::from(10u32).pow(padding);
+            return Ok(Decimal::new(
+                Coefficient::new(sign, magnitude * padding),
+                exponent,
+            ));
+        }
+
+        // The number of digits that will be discarded from the end of the coefficient. If it
+        // doesn't fit in a u32, it's certainly longer than the coefficient itself.
+        let discarded_digits = exponent
+            .checked_sub(self.exponent)
+            .and_then(|difference| u32::try_from(difference).ok())
+            .filter(|discarded| *discarded as usize <= magnitude.to_string().len());
+        let (quotient, remainder_is_zero, remainder_vs_half) = match discarded_digits {
+            // Every digit (and at least one leading zero) will be discarded, so the
+            // remainder is the entire magnitude and is always less than half.
+            None => (BigUint::zero(), magnitude.is_zero(), Ordering::Less),
+            Some(discarded_digits) => {
+                let divisor = BigUint::from(10u32).pow(discarded_digits);
+                let quotient = &magnitude / &divisor;
+                let remainder = &magnitude % &divisor;
+                let remainder_is_zero = remainder.is_zero();
+                (
+                    quotient,
+                    remainder_is_zero,
+                    (remainder * 2u32).cmp(&divisor),
+                )
+            }
+        };
+
+        let away_from_zero = match mode {
+            // If no value was discarded, there's nothing to round.
+            _ if remainder_is_zero => false,
+            RoundingMode::Up => true,
+            RoundingMode::Down => false,
+            RoundingMode::Ceiling => sign == Sign::Positive,
+            RoundingMode::Floor => sign == Sign::Negative,
+            RoundingMode::HalfUp => remainder_vs_half != Ordering::Less,
+            RoundingMode::HalfDown => remainder_vs_half == Ordering::Greater,
+            RoundingMode::HalfEven => match remainder_vs_half {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => (&quotient % 2u32) == BigUint::from(1u32),
+            },
+        };
+        let magnitude = if away_from_zero {
+            quotient + 1u32
+        } else {
+            quotient
+        };
+        Ok(Decimal::new(
+            Coefficient::new(sign, Magnitude::from(magnitude)),
+            exponent,
+        ))
+    }
+
+    /// Returns a Decimal with the same coefficient magnitude and exponent as this one, but with the
+    /// opposite sign. Precision is preserved, so negating `2.50` produces `-2.50`. Zeros are
+    /// negated too: negating `0.` produces `-0.` and vice versa.
+    pub fn neg(&self) -> Decimal {
+        let sign = match self.coefficient.sign() {
+            Sign::Negative => Sign::Positive,
+            Sign::Positive => Sign::Negative,
+        };
+        self.with_sign(sign)
+    }
+
+    /// Returns a Decimal with the same coefficient magnitude and exponent as this one, but with a
+    /// positive sign. Precision is preserved, so the absolute value of `-2.50` is `2.50`, and the
+    /// absolute value of `-0.` is `0.`.
+    pub fn abs(&self) -> Decimal {
+        self.with_sign(Sign::Positive)
+    }
+
+    /// Compares two decimals using a total ordering that is consistent with Ion equivalence:
+    /// two decimals are `Equal` only if they have the same sign, coefficient, and exponent.
+    /// This differs from [Ord], which considers `1.0` and `1.00` to be equal.
+    ///
+    /// Decimals are ordered by numeric value first. Decimals with the same numeric value are then
+    /// ordered negative zero before positive zero, and then from least to most precise. For
+    /// example, `-0. < 0. < 0.0 < 1.0 < 1.00 < 2.`. This is useful for collections such as sorted
+    /// sets, where values that are numerically equal but distinct in Ion must not be merged.
+    pub fn compare_ion(&self, other: &Decimal) -> Ordering {
+        let numeric_ordering = if self.coefficient.is_zero() && other.coefficient.is_zero() {
+            // Zeros are numerically equal regardless of their signs.
+            Ordering::Equal
+        } else {
+            Decimal::compare(self, other)
+        };
+        numeric_ordering
+            .then_with(|| self.coefficient.sign().cmp(&other.coefficient.sign()))
+            // A larger exponent means fewer digits of precision.
+            .then_with(|| other.exponent.cmp(&self.exponent))
+    }
+
+    fn with_sign(&self, sign: Sign) -> Decimal {
+        let magnitude = self.coefficient.magnitude().clone();
+        Decimal::new(Coefficient::new(sign, magnitude), self.exponent)
+    }
+
     // Determines whether the first decimal value is greater than, equal to, or less than
     // the second decimal value.
     // TODO: This currently uses the rules for Ion equivalence to determine if two values are equal.
@@ -276,8 +474,9 @@ impl TryFrom<Decimal> for BigDecimal {
 mod decimal_tests {
     use crate::result::IonResult;
     use crate::types::coefficient::{Coefficient, Sign};
-    use crate::types::decimal::Decimal;
+    use crate::types::decimal::{Decimal, RoundingMode};
     use bigdecimal::BigDecimal;
+    use num_bigint::{BigUint, ToBigUint};
     use num_traits::{Float, ToPrimitive};
     use std::cmp::Ordering;
     use std::convert::TryInto;
@@ -363,6 +562,211 @@ mod decimal_tests {
         assert!(conversion_result.is_err());
     }
 
+    #[test]
+    fn test_decimal_normalized() {
+        // 1.200, 120d-2, and 12000000d-7 are all normalized to 12d-1
+        for decimal in &[
+            Decimal::new(1200, -3),
+            Decimal::new(120, -2),
+            Decimal::new(12_000_000, -7),
+        ] {
+            let normalized = decimal.normalized();
+            assert_eq!(
+                normalized.coefficient,
+                Coefficient::new(Sign::Positive, 12u64)
+            );
+            assert_eq!(normalized.exponent, -1);
+            assert_eq!(&normalized, decimal);
+        }
+        // 120d1 is normalized to 12d2
+        let normalized = Decimal::new(120, 1).normalized();
+        assert_eq!(
+            normalized.coefficient,
+            Coefficient::new(Sign::Positive, 12u64)
+        );
+        assert_eq!(normalized.exponent, 2);
+        // Negative values keep their sign
+        let normalized = Decimal::new(-500, 0).normalized();
+        assert_eq!(
+            normalized.coefficient,
+            Coefficient::new(Sign::Negative, 5u64)
+        );
+        assert_eq!(normalized.exponent, 2);
+        // Zeros are normalized to 0d0 and -0d0
+        let normalized = Decimal::new(0, -5).normalized();
+        assert_eq!(
+            normalized.coefficient,
+            Coefficient::new(Sign::Positive, 0u64)
+        );
+        assert_eq!(normalized.exponent, 0);
+        let normalized = Decimal::negative_zero_with_exponent(3).normalized();
+        assert_eq!(normalized.coefficient, Coefficient::negative_zero());
+        assert_eq!(normalized.exponent, 0);
+    }
+
+    #[rstest]
+    #[case(Decimal::new(2555, -3), 2, RoundingMode::HalfUp, Decimal::new(256, -2))]
+    #[case(Decimal::new(2555, -3), 2, RoundingMode::HalfEven, Decimal::new(256, -2))]
+    #[case(Decimal::new(2545, -3), 2, RoundingMode::HalfEven, Decimal::new(254, -2))]
+    #[case(Decimal::new(2545, -3), 2, RoundingMode::HalfUp, Decimal::new(255, -2))]
+    #[case(Decimal::new(2545, -3), 2, RoundingMode::HalfDown, Decimal::new(254, -2))]
+    #[case(Decimal::new(2546, -3), 2, RoundingMode::HalfDown, Decimal::new(255, -2))]
+    #[case(Decimal::new(2541, -3), 2, RoundingMode::Up, Decimal::new(255, -2))]
+    #[case(Decimal::new(2549, -3), 2, RoundingMode::Down, Decimal::new(254, -2))]
+    #[case(Decimal::new(-2541, -3), 2, RoundingMode::Ceiling, Decimal::new(-254, -2))]
+    #[case(Decimal::new(-2541, -3), 2, RoundingMode::Floor, Decimal::new(-255, -2))]
+    #[case(Decimal::new(2541, -3), 2, RoundingMode::Ceiling, Decimal::new(255, -2))]
+    #[case(Decimal::new(2541, -3), 2, RoundingMode::Floor, Decimal::new(254, -2))]
+    #[case(Decimal::new(-2545, -3), 2, RoundingMode::HalfUp, Decimal::new(-255, -2))]
+    // Exact values are unchanged by any rounding mode
+    #[case(Decimal::new(2540, -3), 2, RoundingMode::Up, Decimal::new(254, -2))]
+    // Values with fewer fractional digits are padded
+    #[case(Decimal::new(25, -1), 3, RoundingMode::HalfEven, Decimal::new(2500, -3))]
+    #[case(Decimal::new(3, 2), 0, RoundingMode::HalfEven, Decimal::new(300, 0))]
+    // Negative scales round to the left of the decimal point
+    #[case(Decimal::new(1250, 0), -2, RoundingMode::HalfEven, Decimal::new(12, 2))]
+    // Discarding every digit
+    #[case(Decimal::new(9, -30), 2, RoundingMode::Up, Decimal::new(1, -2))]
+    #[case(Decimal::new(9, -30), 2, RoundingMode::HalfUp, Decimal::new(0, -2))]
+    #[case(Decimal::new(-1, -3), 2, RoundingMode::HalfEven, Decimal::negative_zero_with_exponent(-2))]
+    fn test_decimal_round(
+        #[case] decimal: Decimal,
+        #[case] scale: i64,
+        #[case] mode: RoundingMode,
+        #[case] expected: Decimal,
+    ) -> IonResult<()> {
+        let rounded = decimal.round(scale, mode)?;
+        assert_eq!(rounded, expected);
+        assert_eq!(rounded.exponent, -scale);
+        Ok(())
+    }
+
+    #[rstest]
+    // -i64::MIN can't be represented as an exponent
+    #[case(Decimal::new(2555, -3), i64::MIN)]
+    // Padding would overflow an i64
+    #[case(Decimal::new(5, i64::MAX), 0)]
+    #[case(Decimal::new(5, 0), i64::MAX)]
+    // Padding fits in a u32, but would allocate a coefficient of gigabytes
+    #[case(Decimal::new(5, 0), u32::MAX as i64)]
+    #[case(Decimal::new(5, 0), 10_001)]
+    fn test_decimal_round_error(#[case] decimal: Decimal, #[case] scale: i64) {
+        assert!(decimal.round(scale, RoundingMode::HalfUp).is_err());
+    }
+
+    #[test]
+    fn test_decimal_round_max_padding() -> IonResult<()> {
+        let rounded = Decimal::new(5, 0).round(10_000, RoundingMode::HalfUp)?;
+        assert_eq!(rounded.exponent, -10_000);
+        assert_eq!(
+            rounded.coefficient.magnitude().to_biguint(),
+            Some(BigUint::from(5u32) * BigUint::from(10u32).pow(10_000))
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    // The number of discarded digits overflows an i64
+    #[case(Decimal::new(9, i64::MIN), -i64::MAX, RoundingMode::Up, Decimal::new(1, i64::MAX))]
+    #[case(Decimal::new(9, i64::MIN), -i64::MAX, RoundingMode::HalfUp, Decimal::new(0, i64::MAX))]
+    fn test_decimal_round_extreme_scales(
+        #[case] decimal: Decimal,
+        #[case] scale: i64,
+        #[case] mode: RoundingMode,
+        #[case] expected: Decimal,
+    ) -> IonResult<()> {
+        assert_eq!(decimal.round(scale, mode)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    // 2.50 => -2.50, 2.50
+    #[case(Decimal::new(250, -2), Decimal::new(-250, -2), Decimal::new(250, -2))]
+    // -2.50 => 2.50, 2.50
+    #[case(Decimal::new(-250, -2), Decimal::new(250, -2), Decimal::new(250, -2))]
+    // 0. => -0., 0.
+    #[case(Decimal::new(0, 0), Decimal::negative_zero(), Decimal::new(0, 0))]
+    // -0. => 0., 0.
+    #[case(Decimal::negative_zero(), Decimal::new(0, 0), Decimal::new(0, 0))]
+    // -0.00 => 0.00, 0.00
+    #[case(
+        Decimal::negative_zero_with_exponent(-2),
+        Decimal::new(0, -2),
+        Decimal::new(0, -2)
+    )]
+    fn test_decimal_neg_and_abs(
+        #[case] decimal: Decimal,
+        #[case] negated: Decimal,
+        #[case] absolute: Decimal,
+    ) {
+        let actual_negated = decimal.neg();
+        assert_eq!(actual_negated, negated);
+        assert_eq!(
+            actual_negated.coefficient.sign(),
+            negated.coefficient.sign()
+        );
+        assert_eq!(actual_negated.exponent, decimal.exponent);
+        let actual_absolute = decimal.abs();
+        assert_eq!(actual_absolute, absolute);
+        assert_eq!(actual_absolute.coefficient.sign(), Sign::Positive);
+        assert_eq!(actual_absolute.exponent, decimal.exponent);
+    }
+
+    #[rstest]
+    // -0. < 0.
+    #[case(Decimal::negative_zero(), Ordering::Less, Decimal::new(0, 0))]
+    // -0.0 < 0.
+    #[case(Decimal::negative_zero_with_exponent(-1), Ordering::Less, Decimal::new(0, 0))]
+    // 0. < 0.0
+    #[case(Decimal::new(0, 0), Ordering::Less, Decimal::new(0, -1))]
+    // 0.0 < 1.0
+    #[case(Decimal::new(0, -1), Ordering::Less, Decimal::new(10, -1))]
+    // 1.0 < 1.00
+    #[case(Decimal::new(10, -1), Ordering::Less, Decimal::new(100, -2))]
+    // 1.00 < 2.
+    #[case(Decimal::new(100, -2), Ordering::Less, Decimal::new(2, 0))]
+    // -1.0 < -1.00
+    #[case(Decimal::new(-100, -2), Ordering::Greater, Decimal::new(-10, -1))]
+    // -1.0 < -0.
+    #[case(Decimal::new(-10, -1), Ordering::Less, Decimal::negative_zero())]
+    // 1.00 == 1.00
+    #[case(Decimal::new(100, -2), Ordering::Equal, Decimal::new(100, -2))]
+    // -0. == -0.
+    #[case(Decimal::negative_zero(), Ordering::Equal, Decimal::negative_zero())]
+    fn test_decimal_compare_ion(
+        #[case] decimal1: Decimal,
+        #[case] ordering: Ordering,
+        #[case] decimal2: Decimal,
+    ) {
+        assert_eq!(decimal1.compare_ion(&decimal2), ordering);
+        assert_eq!(decimal2.compare_ion(&decimal1), ordering.reverse());
+    }
+
+    #[test]
+    fn test_decimal_compare_ion_sort() {
+        let mut decimals = [
+            Decimal::new(100, -2),
+            Decimal::new(0, 0),
+            Decimal::new(10, -1),
+            Decimal::negative_zero(),
+        ];
+        decimals.sort_by(Decimal::compare_ion);
+        let expected = [
+            Decimal::negative_zero(),
+            Decimal::new(0, 0),
+            Decimal::new(10, -1),
+            Decimal::new(100, -2),
+        ];
+        for (actual, expected) in decimals.iter().zip(expected.iter()) {
+            assert_eq!(actual.compare_ion(expected), Ordering::Equal);
+        }
+        // Numeric ordering considers 1.0 and 1.00 to be equal
+        assert_eq!(
+            Decimal::new(10, -1).cmp(&Decimal::new(100, -2)),
+            Ordering::Equal
+        );
+    }
+
     #[test]
     fn test_convert_to_big_decimal() {
         let decimal = Decimal::new(-24601, -3);
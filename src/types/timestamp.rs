@@ -84,9 +84,9 @@ pub struct Timestamp {
     pub(crate) fractional_seconds: Option<Mantissa>,
 }
 
-// TODO: Timestamp does not yet provide useful accessors for its individual fields. It can be
-//       instantiated and tested for equality, but will not very useful as a general purpose
-//       datetime until these methods are added.
+// TODO: Timestamp does not yet provide accessors for its time fields (hour, minute, second, and
+//       fractional seconds). It will not be very useful as a general purpose datetime until
+//       these methods are added.
 impl Timestamp {
     /// Converts a [NaiveDateTime] or [DateTime<FixedOffset>] to a Timestamp with the specified
     /// precision. If the precision is [Precision::FractionalSeconds], nanosecond precision is
@@ -189,6 +189,40 @@ impl Timestamp {
         }
     }
 
+    /// Returns the most precise time unit that has been specified in this Timestamp.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Returns this Timestamp's year.
+    pub fn year(&self) -> u32 {
+        self.date_time.year() as u32
+    }
+
+    /// If the precision is [Precision::Month] or greater, returns this Timestamp's 1-based month;
+    /// otherwise, returns None.
+    pub fn month(&self) -> Option<u32> {
+        if self.precision < Precision::Month {
+            return None;
+        }
+        Some(self.date_time.month())
+    }
+
+    /// If the precision is [Precision::Day] or greater, returns this Timestamp's 1-based day of
+    /// the month; otherwise, returns None.
+    pub fn day(&self) -> Option<u32> {
+        if self.precision < Precision::Day {
+            return None;
+        }
+        Some(self.date_time.day())
+    }
+
+    /// Returns this Timestamp's offset from UTC. If the offset is unknown, returns None.
+    /// Timestamps with a precision coarser than [Precision::HourAndMinute] never have an offset.
+    pub fn offset(&self) -> Option<FixedOffset> {
+        self.offset
+    }
+
     // ============================================================================
     // ====== The below methods are public entry points for the builder API. ======
     // ============================================================================
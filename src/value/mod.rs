@@ -213,6 +213,7 @@ use std::fmt::Debug;
 
 pub mod borrowed;
 pub mod owned;
+pub mod owned_reader;
 pub mod reader;
 pub mod writer;
 
@@ -70,6 +70,16 @@ impl OwnedSymbolToken {
             source,
         }
     }
+
+    /// Returns the text of this token, if it is known.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_ref().map(|text| text.as_ref())
+    }
+
+    /// Returns the local symbol ID of this token, if it has one.
+    pub fn sid(&self) -> Option<SymbolId> {
+        self.local_sid
+    }
 }
 
 /// Constructs an [`OwnedSymbolToken`] with unknown text and a local ID.
@@ -418,6 +428,10 @@ impl OwnedElement {
     pub fn new(annotations: Vec<OwnedSymbolToken>, value: OwnedValue) -> Self {
         Self { annotations, value }
     }
+
+    pub(crate) fn value(&self) -> &OwnedValue {
+        &self.value
+    }
 }
 
 impl PartialEq for OwnedElement {
@@ -628,4 +642,15 @@ mod value_tests {
         // assert if both the element construction creates the same element
         assert_eq!(elem1, elem2);
     }
+
+    #[test]
+    fn owned_symbol_token_accessors() {
+        let token = text_token("foo");
+        assert_eq!(token.text(), Some("foo"));
+        assert_eq!(token.sid(), None);
+
+        let token = local_sid_token(10);
+        assert_eq!(token.text(), None);
+        assert_eq!(token.sid(), Some(10));
+    }
 }
@@ -0,0 +1,471 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Provides a [`RawReader`] implementation that traverses in-memory [`OwnedElement`]s.
+//!
+//! Wrapping an [`OwnedElementReader`] in a [`SystemReader`](crate::SystemReader) makes it
+//! possible to drive the same reader-based code over materialized values that would otherwise
+//! be used to process text or binary input.
+
+use crate::raw_reader::{RawReader, RawStreamItem};
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::value::owned::{OwnedElement, OwnedSymbolToken, OwnedValue};
+use crate::value::{AnyInt, Element, Sequence, Struct, SymbolToken};
+use crate::IonType;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset};
+use num_traits::ToPrimitive;
+
+/// The values at a single depth of the reader, along with the field names of any struct fields.
+/// The values are borrowed from the tree, so stepping into a container only costs a reference
+/// per child.
+#[derive(Debug)]
+struct Level<'a> {
+    values: Vec<(Option<RawSymbolToken>, &'a OwnedElement)>,
+    // The index of the current value, if the reader has visited any values at this depth.
+    index: Option<usize>,
+}
+
+impl<'a> Level<'a> {
+    fn new(values: Vec<(Option<RawSymbolToken>, &'a OwnedElement)>) -> Self {
+        Level {
+            values,
+            index: None,
+        }
+    }
+
+    fn current(&self) -> Option<&(Option<RawSymbolToken>, &'a OwnedElement)> {
+        self.index.and_then(|index| self.values.get(index))
+    }
+}
+
+/// A [`RawReader`] that traverses a sequence of borrowed [`OwnedElement`]s as though they were
+/// the top-level values of an Ion stream.
+#[derive(Debug)]
+pub struct OwnedElementReader<'a> {
+    // The top level is always the first entry in the stack.
+    levels: Vec<Level<'a>>,
+    // The annotations of the current value, converted to their raw representation.
+    annotations: Vec<RawSymbolToken>,
+}
+
+impl<'a> OwnedElementReader<'a> {
+    /// Constructs a reader whose top-level values are the provided elements.
+    pub fn new<I: IntoIterator<Item = &'a OwnedElement>>(elements: I) -> OwnedElementReader<'a> {
+        let values = elements
+            .into_iter()
+            .map(|element| (None, element))
+            .collect();
+        OwnedElementReader {
+            levels: vec![Level::new(values)],
+            annotations: Vec::new(),
+        }
+    }
+
+    fn current_level(&self) -> &Level<'a> {
+        // The top level is never removed from the stack.
+        self.levels.last().unwrap()
+    }
+
+    fn current_element(&self) -> Option<&'a OwnedElement> {
+        self.current_level().current().map(|(_, element)| *element)
+    }
+
+    fn current_value(&self) -> Option<&'a OwnedValue> {
+        self.current_element().map(|element| element.value())
+    }
+}
+
+/// Converts an [`OwnedSymbolToken`] into the [`RawSymbolToken`] that would have been read from
+/// an Ion stream. Tokens without text or a local symbol ID are treated as symbol zero.
+fn raw_symbol_token(token: &OwnedSymbolToken) -> RawSymbolToken {
+    match (token.text(), token.local_sid()) {
+        (Some(text), _) => RawSymbolToken::Text(text.to_owned()),
+        (None, Some(sid)) => RawSymbolToken::SymbolId(sid),
+        (None, None) => RawSymbolToken::SymbolId(0),
+    }
+}
+
+impl<'a> RawReader for OwnedElementReader<'a> {
+    fn ion_version(&self) -> (u8, u8) {
+        (1, 0)
+    }
+
+    fn next(&mut self) -> IonResult<Option<RawStreamItem>> {
+        let level = self.levels.last_mut().unwrap();
+        let next_index = level.index.map(|index| index + 1).unwrap_or(0);
+        // Don't advance past the end; this allows `next()` to be called repeatedly at the end
+        // of a container.
+        level.index = Some(next_index.min(level.values.len()));
+        self.annotations.clear();
+        let element = match self.current_element() {
+            Some(element) => element,
+            None => return Ok(None),
+        };
+        let annotations = element.annotations().map(raw_symbol_token).collect();
+        let item = RawStreamItem::Value(element.ion_type(), element.is_null());
+        self.annotations = annotations;
+        Ok(Some(item))
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        self.current_element().map(|element| element.ion_type())
+    }
+
+    fn is_null(&self) -> bool {
+        self.current_element()
+            .map(|element| element.is_null())
+            .unwrap_or(false)
+    }
+
+    fn annotations(&self) -> &[RawSymbolToken] {
+        &self.annotations
+    }
+
+    fn field_name(&self) -> Option<&RawSymbolToken> {
+        self.current_level()
+            .current()
+            .and_then(|(field_name, _)| field_name.as_ref())
+    }
+
+    fn read_null(&mut self) -> IonResult<Option<IonType>> {
+        match self.current_value() {
+            Some(OwnedValue::Null(ion_type)) => Ok(Some(*ion_type)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_bool(&mut self) -> IonResult<Option<bool>> {
+        match self.current_value() {
+            Some(OwnedValue::Boolean(value)) => Ok(Some(*value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_i64(&mut self) -> IonResult<Option<i64>> {
+        match self.current_value() {
+            Some(OwnedValue::Integer(AnyInt::I64(value))) => Ok(Some(*value)),
+            Some(OwnedValue::Integer(AnyInt::BigInt(value))) => match value.to_i64() {
+                Some(value) => Ok(Some(value)),
+                None => decoding_error(format!("Integer {} is too large to fit in an i64.", value)),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    fn read_f32(&mut self) -> IonResult<Option<f32>> {
+        match self.current_value() {
+            Some(OwnedValue::Float(value)) => Ok(Some(*value as f32)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_f64(&mut self) -> IonResult<Option<f64>> {
+        match self.current_value() {
+            Some(OwnedValue::Float(value)) => Ok(Some(*value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
+        match self.current_value() {
+            Some(OwnedValue::Decimal(value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
+        // TODO: This function is deprecated. Remove it from the trait.
+        // Fails if the value is a negative zero, which BigDecimal cannot represent.
+        self.read_decimal()?.map(BigDecimal::try_from).transpose()
+    }
+
+    fn read_string(&mut self) -> IonResult<Option<String>> {
+        self.string_ref_map(|text| text.to_owned())
+    }
+
+    fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&str) -> T,
+    {
+        match self.current_value() {
+            Some(OwnedValue::String(text)) => Ok(Some(f(text.as_str()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&[u8]) -> T,
+    {
+        match self.current_value() {
+            Some(OwnedValue::String(text)) => Ok(Some(f(text.as_bytes()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
+        match self.current_value() {
+            Some(OwnedValue::Symbol(token)) => Ok(Some(raw_symbol_token(token))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        self.blob_ref_map(|bytes| bytes.to_vec())
+    }
+
+    fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        match self.current_value() {
+            Some(OwnedValue::Blob(bytes)) => Ok(Some(f(bytes.as_slice()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        self.clob_ref_map(|bytes| bytes.to_vec())
+    }
+
+    fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        match self.current_value() {
+            Some(OwnedValue::Clob(bytes)) => Ok(Some(f(bytes.as_slice()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
+        match self.current_value() {
+            Some(OwnedValue::Timestamp(timestamp)) => Ok(Some(timestamp.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>> {
+        // TODO: This is deprecated. Remove it from the trait.
+        // Fails if the timestamp's offset is unknown.
+        self.read_timestamp()?.map(Timestamp::try_into).transpose()
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        let children = match self.current_value() {
+            Some(OwnedValue::List(sequence)) | Some(OwnedValue::SExpression(sequence)) => {
+                sequence.iter().map(|child| (None, child)).collect()
+            }
+            Some(OwnedValue::Struct(structure)) => structure
+                .iter()
+                .map(|(name, child)| (Some(raw_symbol_token(name)), child))
+                .collect(),
+            Some(OwnedValue::Null(ion_type)) if ion_type.is_container() => {
+                return illegal_operation(format!("Cannot step_in() to a null {:?}", ion_type));
+            }
+            Some(_) => {
+                return illegal_operation(format!(
+                    "Cannot step_in() to a {:?}; it is not a container.",
+                    self.ion_type().unwrap()
+                ));
+            }
+            None => {
+                return illegal_operation(
+                    "Cannot step_in() when the reader is not positioned on a value.",
+                );
+            }
+        };
+        self.levels.push(Level::new(children));
+        self.annotations.clear();
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if self.levels.len() == 1 {
+            return illegal_operation(
+                "Cannot call `step_out()` when the reader is at the top level.",
+            );
+        }
+        let _ = self.levels.pop();
+        // The reader is positioned on the container; restore its annotations.
+        self.annotations = self
+            .current_element()
+            .map(|element| element.annotations().map(raw_symbol_token).collect())
+            .unwrap_or_default();
+        Ok(())
+    }
+
+    fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod owned_reader_tests {
+    use super::*;
+    use crate::system_reader::{equivalent_streams, SystemReader, SystemStreamItem};
+    use crate::text::raw_text_reader::RawTextReader;
+    use crate::value::owned::text_token;
+    use crate::value::Builder;
+
+    #[test]
+    fn read_struct_through_system_reader() -> IonResult<()> {
+        let element = OwnedElement::new_struct(vec![
+            ("name", OwnedElement::new_string("widget")),
+            (
+                "sizes",
+                OwnedElement::new_list(vec![OwnedElement::new_i64(1), OwnedElement::new_i64(2)]),
+            ),
+        ])
+        .with_annotations(vec![text_token("product")]);
+        let elements = vec![element, 7i64.into()];
+        let reader = &mut SystemReader::new(OwnedElementReader::new(&elements));
+
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::Value(IonType::Struct, false))
+        );
+        assert_eq!(
+            reader.annotations().collect::<Vec<_>>(),
+            vec![Some("product")]
+        );
+        reader.step_in()?;
+        let mut saw_name = false;
+        let mut saw_sizes = false;
+        while let Some(item) = reader.next()? {
+            match reader.field_name() {
+                Some("name") => {
+                    assert_eq!(item, SystemStreamItem::Value(IonType::String, false));
+                    assert_eq!(reader.read_string()?, Some("widget".to_string()));
+                    saw_name = true;
+                }
+                Some("sizes") => {
+                    assert_eq!(item, SystemStreamItem::Value(IonType::List, false));
+                    reader.step_in()?;
+                    assert_eq!(reader.depth(), 2);
+                    reader.next()?;
+                    assert_eq!(reader.read_i64()?, Some(1));
+                    // Step out without visiting the remaining value.
+                    reader.step_out()?;
+                    saw_sizes = true;
+                }
+                other => panic!("unexpected field name: {:?}", other),
+            }
+        }
+        assert!(saw_name && saw_sizes);
+        reader.step_out()?;
+        assert_eq!(reader.depth(), 0);
+
+        assert_eq!(
+            reader.next()?,
+            Some(SystemStreamItem::Value(IonType::Integer, false))
+        );
+        assert_eq!(reader.read_i64()?, Some(7));
+        assert!(reader.step_in().is_err());
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn read_null_container() -> IonResult<()> {
+        let elements = vec![OwnedElement::new_null(IonType::List)];
+        let reader = &mut OwnedElementReader::new(&elements);
+        assert_eq!(
+            reader.next()?,
+            Some(RawStreamItem::Value(IonType::List, true))
+        );
+        assert_eq!(reader.read_null()?, Some(IonType::List));
+        assert!(reader.step_in().is_err());
+        assert!(reader.step_out().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_deeply_nested_lists() -> IonResult<()> {
+        let depth = 1_000;
+        let mut element = OwnedElement::new_i64(7);
+        for _ in 0..depth {
+            element = OwnedElement::new_list(vec![element]);
+        }
+        let elements = vec![element];
+        let reader = &mut OwnedElementReader::new(&elements);
+        for _ in 0..depth {
+            assert_eq!(
+                reader.next()?,
+                Some(RawStreamItem::Value(IonType::List, false))
+            );
+            reader.step_in()?;
+        }
+        assert_eq!(reader.depth(), depth);
+        reader.next()?;
+        assert_eq!(reader.read_i64()?, Some(7));
+        for _ in 0..depth {
+            reader.step_out()?;
+        }
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn read_deprecated_types() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd_hms(2021, 3, 4, 5, 6, 7).build_at_offset(60)?;
+        let elements = vec![
+            OwnedElement::new_decimal(Decimal::new(25, -1)),
+            OwnedElement::new_decimal(Decimal::negative_zero()),
+            OwnedElement::new_timestamp(timestamp.clone()),
+            OwnedElement::new_timestamp(
+                Timestamp::with_ymd_hms(2021, 3, 4, 5, 6, 7).build_at_unknown_offset()?,
+            ),
+        ];
+        let reader = &mut OwnedElementReader::new(&elements);
+        reader.next()?;
+        assert_eq!(
+            reader.read_big_decimal()?,
+            Some(BigDecimal::new(25.into(), 1))
+        );
+        // BigDecimal cannot represent a negative zero.
+        reader.next()?;
+        assert!(reader.read_big_decimal().is_err());
+        reader.next()?;
+        let expected: DateTime<FixedOffset> = timestamp.try_into()?;
+        assert_eq!(reader.read_datetime()?, Some(expected));
+        // DateTime<FixedOffset> cannot represent an unknown offset.
+        reader.next()?;
+        assert!(reader.read_datetime().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn equivalent_to_text() -> IonResult<()> {
+        let text = r#"foo::{a: 1, b: [true, 2.5e0, "hi", 3.0], c: (bar baz)} null.int {{aGk=}}"#;
+        let list = OwnedElement::new_list(vec![
+            OwnedElement::new_bool(true),
+            OwnedElement::new_f64(2.5),
+            OwnedElement::new_string("hi"),
+            OwnedElement::new_decimal(Decimal::new(30, -1)),
+        ]);
+        let sexp = OwnedElement::new_sexp(vec![
+            OwnedElement::new_symbol(text_token("bar")),
+            OwnedElement::new_symbol(text_token("baz")),
+        ]);
+        let structure = OwnedElement::new_struct(vec![
+            ("a", OwnedElement::new_i64(1)),
+            ("b", list),
+            ("c", sexp),
+        ])
+        .with_annotations(vec![text_token("foo")]);
+        let elements = vec![
+            structure,
+            OwnedElement::new_null(IonType::Integer),
+            OwnedElement::new_blob(b"hi"),
+        ];
+        let elements_reader = &mut SystemReader::new(OwnedElementReader::new(&elements));
+        let text_reader = &mut SystemReader::new(RawTextReader::new(text));
+        assert!(equivalent_streams(text_reader, elements_reader)?);
+        Ok(())
+    }
+}